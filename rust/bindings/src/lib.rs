@@ -4,7 +4,38 @@
 //! library available to Node.js applications with zero-overhead performance.
 
 use neon::prelude::*;
-use financial_math::{PRICE_SCALE, QUANTITY_SCALE};
+use neon::types::buffer::TypedArray;
+use financial_math::{FinancialError, Scale, PRICE_SCALE, QUANTITY_SCALE};
+
+// ===== ERRORS =====
+
+/// Map a `FinancialError` to a stable error code and message for JavaScript
+fn error_code(error: FinancialError) -> (&'static str, String) {
+    let code = match error {
+        FinancialError::Overflow => "OVERFLOW",
+        FinancialError::DivisionByZero => "DIVISION_BY_ZERO",
+        FinancialError::InvalidScale => "INVALID_SCALE",
+        FinancialError::NegativeValue => "NEGATIVE_VALUE",
+        FinancialError::InvalidValue | FinancialError::InvalidValueAt(_) => "INVALID_VALUE",
+    };
+
+    (code, format!("{:?}", error))
+}
+
+/// Throw a JS `Error` whose `code` property identifies the `FinancialError`
+fn throw_financial_error<'a, C: Context<'a>, T>(
+    cx: &mut C,
+    context: &str,
+    error: FinancialError,
+) -> NeonResult<T> {
+    let (code, message) = error_code(error);
+
+    let js_error = cx.error(format!("{}: {}", context, message))?;
+    let js_code = cx.string(code);
+    js_error.set(cx, "code", js_code)?;
+
+    cx.throw(js_error)
+}
 
 // ===== CONVERSIONS =====
 
@@ -16,7 +47,21 @@ fn price_to_int(mut cx: FunctionContext) -> JsResult<JsString> {
 
     let result = match financial_math::conversions::price_to_int(price) {
         Ok(value) => value,
-        Err(e) => return cx.throw_error(&format!("Conversion error: {:?}", e)),
+        Err(e) => return throw_financial_error(&mut cx, "Conversion error", e),
+    };
+
+    Ok(cx.string(result.to_string()))
+}
+
+fn price_string_to_int(mut cx: FunctionContext) -> JsResult<JsString> {
+    let price_str = match cx.argument::<JsString>(0) {
+        Ok(arg) => arg.value(&mut cx),
+        Err(_) => return cx.throw_error("Expected string argument"),
+    };
+
+    let result = match financial_math::conversions::decimal_str_to_int(&price_str, PRICE_SCALE.value()) {
+        Ok(value) => value,
+        Err(e) => return throw_financial_error(&mut cx, "Conversion error", e),
     };
 
     Ok(cx.string(result.to_string()))
@@ -45,7 +90,7 @@ fn quantity_to_int(mut cx: FunctionContext) -> JsResult<JsString> {
 
     let result = match financial_math::conversions::quantity_to_int(quantity) {
         Ok(value) => value,
-        Err(e) => return cx.throw_error(&format!("Conversion error: {:?}", e)),
+        Err(e) => return throw_financial_error(&mut cx, "Conversion error", e),
     };
 
     Ok(cx.string(result.to_string()))
@@ -66,6 +111,128 @@ fn int_to_quantity(mut cx: FunctionContext) -> JsResult<JsNumber> {
     Ok(cx.number(result))
 }
 
+fn prices_to_int(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let prices_array = match cx.argument::<JsFloat64Array>(0) {
+        Ok(arg) => arg,
+        Err(_) => return cx.throw_error("Expected Float64Array argument"),
+    };
+
+    let prices = prices_array.as_slice(&cx).to_vec();
+
+    let results = match financial_math::conversions::prices_to_int(&prices) {
+        Ok(values) => values,
+        Err(e) => return throw_financial_error(&mut cx, "Conversion error", e),
+    };
+
+    let js_array = cx.empty_array();
+    for (index, value) in results.iter().enumerate() {
+        let js_value = cx.string(value.to_string());
+        js_array.set(&mut cx, index as u32, js_value)?;
+    }
+
+    Ok(js_array)
+}
+
+fn ints_to_prices(mut cx: FunctionContext) -> JsResult<JsFloat64Array> {
+    let values_array = match cx.argument::<JsArray>(0) {
+        Ok(arg) => arg,
+        Err(_) => return cx.throw_error("Expected array argument"),
+    };
+
+    let values_vec: Vec<Handle<JsValue>> = match values_array.to_vec(&mut cx) {
+        Ok(vec) => vec,
+        Err(_) => return cx.throw_error("Failed to convert array to vector"),
+    };
+    let mut values_u128 = Vec::new();
+
+    for value in values_vec {
+        let value_str = match value.downcast::<JsString, _>(&mut cx) {
+            Ok(str_handle) => str_handle,
+            Err(_) => return cx.throw_error("Expected string in array"),
+        };
+
+        let value_u128: u128 = match value_str.value(&mut cx).parse() {
+            Ok(parsed) => parsed,
+            Err(_) => return cx.throw_error("Invalid u128 value in array"),
+        };
+
+        values_u128.push(value_u128);
+    }
+
+    let results = financial_math::conversions::ints_to_prices(&values_u128);
+    JsFloat64Array::from_slice(&mut cx, &results)
+}
+
+/// Largest scale whose multiplier (10^scale) fits in a u128
+const MAX_SCALE: f64 = 38.0;
+
+/// Convert a JS number to a scale, rejecting non-integers and values outside 0..=MAX_SCALE
+fn scale_from_number(scale: f64) -> Option<u32> {
+    if scale.fract() != 0.0 || !(0.0..=MAX_SCALE).contains(&scale) {
+        return None;
+    }
+    Some(scale as u32)
+}
+
+fn float_to_fixed(mut cx: FunctionContext) -> JsResult<JsString> {
+    let value = match cx.argument::<JsNumber>(0) {
+        Ok(arg) => arg.value(&mut cx),
+        Err(_) => return cx.throw_error("Expected number argument for value"),
+    };
+
+    let scale = match cx.argument::<JsNumber>(1) {
+        Ok(arg) => arg.value(&mut cx),
+        Err(_) => return cx.throw_error("Expected number argument for scale"),
+    };
+
+    let scale = match scale_from_number(scale) {
+        Some(value) => value,
+        None => return throw_financial_error(&mut cx, "Conversion error", FinancialError::InvalidScale),
+    };
+
+    let scale = match financial_math::validation::validate_scale(scale) {
+        Ok(value) => value,
+        Err(e) => return throw_financial_error(&mut cx, "Conversion error", e),
+    };
+
+    let result = match financial_math::conversions::float_to_fixed(value, Scale::Custom(scale)) {
+        Ok(value) => value,
+        Err(e) => return throw_financial_error(&mut cx, "Conversion error", e),
+    };
+
+    Ok(cx.string(result.to_string()))
+}
+
+fn fixed_to_float(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let value_str = match cx.argument::<JsString>(0) {
+        Ok(arg) => arg.value(&mut cx),
+        Err(_) => return cx.throw_error("Expected string argument for value"),
+    };
+
+    let scale = match cx.argument::<JsNumber>(1) {
+        Ok(arg) => arg.value(&mut cx),
+        Err(_) => return cx.throw_error("Expected number argument for scale"),
+    };
+
+    let scale = match scale_from_number(scale) {
+        Some(value) => value,
+        None => return throw_financial_error(&mut cx, "Conversion error", FinancialError::InvalidScale),
+    };
+
+    let scale = match financial_math::validation::validate_scale(scale) {
+        Ok(value) => value,
+        Err(e) => return throw_financial_error(&mut cx, "Conversion error", e),
+    };
+
+    let value_u128: u128 = match value_str.parse() {
+        Ok(value) => value,
+        Err(_) => return cx.throw_error("Invalid u128 value"),
+    };
+
+    let result = financial_math::conversions::fixed_to_float(value_u128, Scale::Custom(scale));
+    Ok(cx.number(result))
+}
+
 // ===== ARITHMETIC =====
 
 fn safe_add(mut cx: FunctionContext) -> JsResult<JsString> {
@@ -91,7 +258,7 @@ fn safe_add(mut cx: FunctionContext) -> JsResult<JsString> {
 
     let result = match financial_math::arithmetic::safe_add(a_u128, b_u128) {
         Ok(value) => value,
-        Err(e) => return cx.throw_error(&format!("Arithmetic error: {:?}", e)),
+        Err(e) => return throw_financial_error(&mut cx, "Arithmetic error", e),
     };
 
     Ok(cx.string(result.to_string()))
@@ -120,7 +287,7 @@ fn safe_subtract(mut cx: FunctionContext) -> JsResult<JsString> {
 
     let result = match financial_math::arithmetic::safe_subtract(a_u128, b_u128) {
         Ok(value) => value,
-        Err(e) => return cx.throw_error(&format!("Arithmetic error: {:?}", e)),
+        Err(e) => return throw_financial_error(&mut cx, "Arithmetic error", e),
     };
 
     Ok(cx.string(result.to_string()))
@@ -149,7 +316,7 @@ fn safe_multiply(mut cx: FunctionContext) -> JsResult<JsString> {
 
     let result = match financial_math::arithmetic::safe_multiply(a_u128, b_u128) {
         Ok(value) => value,
-        Err(e) => return cx.throw_error(&format!("Arithmetic error: {:?}", e)),
+        Err(e) => return throw_financial_error(&mut cx, "Arithmetic error", e),
     };
 
     Ok(cx.string(result.to_string()))
@@ -178,12 +345,47 @@ fn safe_divide(mut cx: FunctionContext) -> JsResult<JsString> {
 
     let result = match financial_math::arithmetic::safe_divide(a_u128, b_u128) {
         Ok(value) => value,
-        Err(e) => return cx.throw_error(&format!("Arithmetic error: {:?}", e)),
+        Err(e) => return throw_financial_error(&mut cx, "Arithmetic error", e),
     };
 
     Ok(cx.string(result.to_string()))
 }
 
+fn divide_with_remainder(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let numerator_str = match cx.argument::<JsString>(0) {
+        Ok(arg) => arg.value(&mut cx),
+        Err(_) => return cx.throw_error("Expected string argument for numerator"),
+    };
+
+    let denominator_str = match cx.argument::<JsString>(1) {
+        Ok(arg) => arg.value(&mut cx),
+        Err(_) => return cx.throw_error("Expected string argument for denominator"),
+    };
+
+    let numerator_u128: u128 = match numerator_str.parse() {
+        Ok(value) => value,
+        Err(_) => return cx.throw_error("Invalid u128 value for numerator"),
+    };
+
+    let denominator_u128: u128 = match denominator_str.parse() {
+        Ok(value) => value,
+        Err(_) => return cx.throw_error("Invalid u128 value for denominator"),
+    };
+
+    let (quotient, remainder) = match financial_math::arithmetic::divide_with_remainder(numerator_u128, denominator_u128) {
+        Ok(value) => value,
+        Err(e) => return throw_financial_error(&mut cx, "Arithmetic error", e),
+    };
+
+    let js_object = cx.empty_object();
+    let js_quotient = cx.string(quotient.to_string());
+    js_object.set(&mut cx, "quotient", js_quotient)?;
+    let js_remainder = cx.string(remainder.to_string());
+    js_object.set(&mut cx, "remainder", js_remainder)?;
+
+    Ok(js_object)
+}
+
 fn calculate_mid_price(mut cx: FunctionContext) -> JsResult<JsString> {
     let bid_str = match cx.argument::<JsString>(0) {
         Ok(arg) => arg.value(&mut cx),
@@ -230,7 +432,73 @@ fn calculate_spread(mut cx: FunctionContext) -> JsResult<JsString> {
         Err(_) => return cx.throw_error("Invalid u128 value for ask"),
     };
 
-    let result = financial_math::arithmetic::calculate_spread(bid_u128, ask_u128);
+    // Core signature is calculate_spread(ask, bid)
+    let result = financial_math::arithmetic::calculate_spread(ask_u128, bid_u128);
+    Ok(cx.string(result.to_string()))
+}
+
+fn notional(mut cx: FunctionContext) -> JsResult<JsString> {
+    let price_str = match cx.argument::<JsString>(0) {
+        Ok(arg) => arg.value(&mut cx),
+        Err(_) => return cx.throw_error("Expected string argument for price"),
+    };
+
+    let price_scale = match cx.argument::<JsNumber>(1) {
+        Ok(arg) => arg.value(&mut cx),
+        Err(_) => return cx.throw_error("Expected number argument for price_scale"),
+    };
+
+    let price_scale = match scale_from_number(price_scale) {
+        Some(value) => value,
+        None => return throw_financial_error(&mut cx, "Invalid price_scale", FinancialError::InvalidScale),
+    };
+
+    let quantity_str = match cx.argument::<JsString>(2) {
+        Ok(arg) => arg.value(&mut cx),
+        Err(_) => return cx.throw_error("Expected string argument for quantity"),
+    };
+
+    let qty_scale = match cx.argument::<JsNumber>(3) {
+        Ok(arg) => arg.value(&mut cx),
+        Err(_) => return cx.throw_error("Expected number argument for qty_scale"),
+    };
+
+    let qty_scale = match scale_from_number(qty_scale) {
+        Some(value) => value,
+        None => return throw_financial_error(&mut cx, "Invalid qty_scale", FinancialError::InvalidScale),
+    };
+
+    let out_scale = match cx.argument::<JsNumber>(4) {
+        Ok(arg) => arg.value(&mut cx),
+        Err(_) => return cx.throw_error("Expected number argument for out_scale"),
+    };
+
+    let out_scale = match scale_from_number(out_scale) {
+        Some(value) => value,
+        None => return throw_financial_error(&mut cx, "Invalid out_scale", FinancialError::InvalidScale),
+    };
+
+    let price_u128: u128 = match price_str.parse() {
+        Ok(value) => value,
+        Err(_) => return cx.throw_error("Invalid u128 value for price"),
+    };
+
+    let quantity_u128: u128 = match quantity_str.parse() {
+        Ok(value) => value,
+        Err(_) => return cx.throw_error("Invalid u128 value for quantity"),
+    };
+
+    let result = match financial_math::arithmetic::notional(
+        price_u128,
+        price_scale,
+        quantity_u128,
+        qty_scale,
+        out_scale,
+    ) {
+        Ok(value) => value,
+        Err(e) => return throw_financial_error(&mut cx, "Arithmetic error", e),
+    };
+
     Ok(cx.string(result.to_string()))
 }
 
@@ -264,7 +532,7 @@ fn calculate_mean(mut cx: FunctionContext) -> JsResult<JsString> {
 
     let result = match financial_math::statistics::calculate_mean(&values_u128) {
         Ok(value) => value,
-        Err(e) => return cx.throw_error(&format!("Statistics error: {:?}", e)),
+        Err(e) => return throw_financial_error(&mut cx, "Statistics error", e),
     };
 
     Ok(cx.string(result.to_string()))
@@ -298,7 +566,7 @@ fn calculate_median(mut cx: FunctionContext) -> JsResult<JsString> {
 
     let result = match financial_math::statistics::calculate_median(&values_u128) {
         Ok(value) => value,
-        Err(e) => return cx.throw_error(&format!("Statistics error: {:?}", e)),
+        Err(e) => return throw_financial_error(&mut cx, "Statistics error", e),
     };
 
     Ok(cx.string(result.to_string()))
@@ -332,7 +600,7 @@ fn calculate_min(mut cx: FunctionContext) -> JsResult<JsString> {
 
     let result = match financial_math::statistics::calculate_min(&values_u128) {
         Ok(value) => value,
-        Err(e) => return cx.throw_error(&format!("Statistics error: {:?}", e)),
+        Err(e) => return throw_financial_error(&mut cx, "Statistics error", e),
     };
 
     Ok(cx.string(result.to_string()))
@@ -366,12 +634,68 @@ fn calculate_max(mut cx: FunctionContext) -> JsResult<JsString> {
 
     let result = match financial_math::statistics::calculate_max(&values_u128) {
         Ok(value) => value,
-        Err(e) => return cx.throw_error(&format!("Statistics error: {:?}", e)),
+        Err(e) => return throw_financial_error(&mut cx, "Statistics error", e),
     };
 
     Ok(cx.string(result.to_string()))
 }
 
+fn summary_stats(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let values_array = match cx.argument::<JsArray>(0) {
+        Ok(arg) => arg,
+        Err(_) => return cx.throw_error("Expected array argument"),
+    };
+
+    let scale = match cx.argument::<JsNumber>(1) {
+        Ok(arg) => arg.value(&mut cx),
+        Err(_) => return cx.throw_error("Expected number argument for scale"),
+    };
+
+    let scale = match scale_from_number(scale) {
+        Some(value) => value,
+        None => return throw_financial_error(&mut cx, "Statistics error", FinancialError::InvalidScale),
+    };
+
+    let values_vec: Vec<Handle<JsValue>> = match values_array.to_vec(&mut cx) {
+        Ok(vec) => vec,
+        Err(_) => return cx.throw_error("Failed to convert array to vector"),
+    };
+    let mut values_u128 = Vec::new();
+
+    for value in values_vec {
+        let value_str = match value.downcast::<JsString, _>(&mut cx) {
+            Ok(str_handle) => str_handle,
+            Err(_) => return cx.throw_error("Expected string in array"),
+        };
+
+        let value_u128: u128 = match value_str.value(&mut cx).parse() {
+            Ok(parsed) => parsed,
+            Err(_) => return cx.throw_error("Invalid u128 value in array"),
+        };
+
+        values_u128.push(value_u128);
+    }
+
+    let stats = match financial_math::statistics::summary_stats(&values_u128, scale) {
+        Ok(value) => value,
+        Err(e) => return throw_financial_error(&mut cx, "Statistics error", e),
+    };
+
+    let js_object = cx.empty_object();
+    for (key, value) in [
+        ("min", stats.min),
+        ("max", stats.max),
+        ("mean", stats.mean),
+        ("variance", stats.variance),
+        ("stdDev", stats.std_dev),
+    ] {
+        let js_value = cx.string(value.to_string());
+        js_object.set(&mut cx, key, js_value)?;
+    }
+
+    Ok(js_object)
+}
+
 // ===== ZONES =====
 
 fn normalize_price_to_tick(mut cx: FunctionContext) -> JsResult<JsString> {
@@ -397,12 +721,62 @@ fn normalize_price_to_tick(mut cx: FunctionContext) -> JsResult<JsString> {
 
     let result = match financial_math::zones::normalize_price_to_tick(price_u128, tick_size_u128) {
         Ok(value) => value,
-        Err(e) => return cx.throw_error(&format!("Zone error: {:?}", e)),
+        Err(e) => return throw_financial_error(&mut cx, "Zone error", e),
     };
 
     Ok(cx.string(result.to_string()))
 }
 
+fn normalize_prices_to_tick(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let prices_array = match cx.argument::<JsArray>(0) {
+        Ok(arg) => arg,
+        Err(_) => return cx.throw_error("Expected array argument for prices"),
+    };
+
+    let tick_size_str = match cx.argument::<JsString>(1) {
+        Ok(arg) => arg.value(&mut cx),
+        Err(_) => return cx.throw_error("Expected string argument for tick_size"),
+    };
+
+    let prices_vec: Vec<Handle<JsValue>> = match prices_array.to_vec(&mut cx) {
+        Ok(vec) => vec,
+        Err(_) => return cx.throw_error("Failed to convert array to vector"),
+    };
+    let mut prices_u128 = Vec::new();
+
+    for value in prices_vec {
+        let value_str = match value.downcast::<JsString, _>(&mut cx) {
+            Ok(str_handle) => str_handle,
+            Err(_) => return cx.throw_error("Expected string in array"),
+        };
+
+        let value_u128: u128 = match value_str.value(&mut cx).parse() {
+            Ok(parsed) => parsed,
+            Err(_) => return cx.throw_error("Invalid u128 value in array"),
+        };
+
+        prices_u128.push(value_u128);
+    }
+
+    let tick_size_u128: u128 = match tick_size_str.parse() {
+        Ok(value) => value,
+        Err(_) => return cx.throw_error("Invalid u128 value for tick_size"),
+    };
+
+    let results = match financial_math::zones::normalize_prices_to_tick(&prices_u128, tick_size_u128) {
+        Ok(values) => values,
+        Err(e) => return throw_financial_error(&mut cx, "Zone error", e),
+    };
+
+    let js_array = cx.empty_array();
+    for (index, value) in results.iter().enumerate() {
+        let js_value = cx.string(value.to_string());
+        js_array.set(&mut cx, index as u32, js_value)?;
+    }
+
+    Ok(js_array)
+}
+
 fn is_price_in_zone(mut cx: FunctionContext) -> JsResult<JsBoolean> {
     let price_str = match cx.argument::<JsString>(0) {
         Ok(arg) => arg.value(&mut cx),
@@ -456,6 +830,10 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
         Ok(_) => {},
         Err(e) => return Err(e),
     }
+    match cx.export_function("price_string_to_int", price_string_to_int) {
+        Ok(_) => {},
+        Err(e) => return Err(e),
+    }
     match cx.export_function("int_to_price", int_to_price) {
         Ok(_) => {},
         Err(e) => return Err(e),
@@ -468,6 +846,22 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
         Ok(_) => {},
         Err(e) => return Err(e),
     }
+    match cx.export_function("prices_to_int", prices_to_int) {
+        Ok(_) => {},
+        Err(e) => return Err(e),
+    }
+    match cx.export_function("ints_to_prices", ints_to_prices) {
+        Ok(_) => {},
+        Err(e) => return Err(e),
+    }
+    match cx.export_function("float_to_fixed", float_to_fixed) {
+        Ok(_) => {},
+        Err(e) => return Err(e),
+    }
+    match cx.export_function("fixed_to_float", fixed_to_float) {
+        Ok(_) => {},
+        Err(e) => return Err(e),
+    }
     match cx.export_function("safe_add", safe_add) {
         Ok(_) => {},
         Err(e) => return Err(e),
@@ -484,6 +878,10 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
         Ok(_) => {},
         Err(e) => return Err(e),
     }
+    match cx.export_function("divide_with_remainder", divide_with_remainder) {
+        Ok(_) => {},
+        Err(e) => return Err(e),
+    }
     match cx.export_function("calculate_mid_price", calculate_mid_price) {
         Ok(_) => {},
         Err(e) => return Err(e),
@@ -492,6 +890,10 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
         Ok(_) => {},
         Err(e) => return Err(e),
     }
+    match cx.export_function("notional", notional) {
+        Ok(_) => {},
+        Err(e) => return Err(e),
+    }
     match cx.export_function("calculate_mean", calculate_mean) {
         Ok(_) => {},
         Err(e) => return Err(e),
@@ -508,10 +910,18 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
         Ok(_) => {},
         Err(e) => return Err(e),
     }
+    match cx.export_function("summary_stats", summary_stats) {
+        Ok(_) => {},
+        Err(e) => return Err(e),
+    }
     match cx.export_function("normalize_price_to_tick", normalize_price_to_tick) {
         Ok(_) => {},
         Err(e) => return Err(e),
     }
+    match cx.export_function("normalize_prices_to_tick", normalize_prices_to_tick) {
+        Ok(_) => {},
+        Err(e) => return Err(e),
+    }
     match cx.export_function("is_price_in_zone", is_price_in_zone) {
         Ok(_) => {},
         Err(e) => return Err(e),
@@ -3,7 +3,7 @@
 //! Core mathematical operations using u128 fixed-point arithmetic.
 //! All operations are designed for maximum performance with overflow protection.
 
-use crate::{FinancialResult, FinancialError};
+use crate::{FinancialResult, FinancialError, FinancialValue, RoundMode};
 
 /// Safe addition with overflow protection
 ///
@@ -64,8 +64,79 @@ pub fn safe_divide(a: u128, b: u128) -> FinancialResult<u128> {
     Ok(a / b)
 }
 
+/// Division returning both quotient and remainder, with zero-check
+///
+/// # Examples
+/// ```
+/// use financial_math::divide_with_remainder;
+///
+/// // Split 100 units into 3 child orders; the last one takes the leftover
+/// let (per_child, leftover) = divide_with_remainder(100, 3).unwrap();
+/// assert_eq!((per_child, leftover), (33, 1));
+/// ```
+#[inline(always)]
+pub fn divide_with_remainder(numerator: u128, denominator: u128) -> FinancialResult<(u128, u128)> {
+    if denominator == 0 {
+        return Err(FinancialError::DivisionByZero);
+    }
+    Ok((numerator / denominator, numerator % denominator))
+}
+
+/// Saturating addition, clamping to `u128::MAX` on overflow
+///
+/// Use for display aggregates and other values where a clamped result is
+/// acceptable. Prefer `safe_add` wherever an overflow must be noticed, such
+/// as balances, notionals and anything fed back into further calculations.
+///
+/// # Examples
+/// ```
+/// use financial_math::sat_add;
+///
+/// assert_eq!(sat_add(100_000_000, 50_000_000), 150_000_000);
+/// assert_eq!(sat_add(u128::MAX, 1), u128::MAX);
+/// ```
+#[inline(always)]
+pub fn sat_add(a: u128, b: u128) -> u128 {
+    a.saturating_add(b)
+}
+
+/// Saturating multiplication, clamping to `u128::MAX` on overflow
+///
+/// Same trade-off as `sat_add`: prefer `safe_multiply` unless clamping is
+/// explicitly acceptable.
+///
+/// # Examples
+/// ```
+/// use financial_math::sat_multiply;
+///
+/// assert_eq!(sat_multiply(100_000_000, 2), 200_000_000);
+/// assert_eq!(sat_multiply(u128::MAX / 2 + 1, 2), u128::MAX);
+/// ```
+#[inline(always)]
+pub fn sat_multiply(a: u128, b: u128) -> u128 {
+    a.saturating_mul(b)
+}
+
+/// Safe sum of a slice with overflow protection
+///
+/// # Examples
+/// ```
+/// use financial_math::safe_sum;
+///
+/// let result = safe_sum(&[100_000_000, 50_000_000, 25_000_000]).unwrap();
+/// assert_eq!(result, 175_000_000);
+/// ```
+#[inline]
+pub fn safe_sum(values: &[u128]) -> FinancialResult<u128> {
+    values.iter().try_fold(0u128, |acc, &v| safe_add(acc, v))
+}
+
 /// Calculate mid price: (bid + ask) / 2
 ///
+/// Argument order does not matter, so a crossed book (bid > ask) still yields
+/// the true midpoint. Halves are computed separately so the sum never
+/// overflows, and the result is truncated like integer division of the sum.
+///
 /// # Examples
 /// ```
 /// use financial_math::calculate_mid_price;
@@ -77,7 +148,7 @@ pub fn safe_divide(a: u128, b: u128) -> FinancialResult<u128> {
 /// ```
 #[inline(always)]
 pub fn calculate_mid_price(bid: u128, ask: u128) -> u128 {
-    bid + (ask - bid) / 2
+    (bid / 2 + ask / 2) + (bid % 2 + ask % 2) / 2
 }
 
 /// Calculate spread: ask - bid
@@ -128,6 +199,61 @@ pub fn divide_quantities(numerator: u128, denominator: u128) -> FinancialResult<
     safe_divide(numerator, denominator)
 }
 
+/// Calculate notional value (price × quantity) rescaled to `out_scale`
+///
+/// The raw product carries `price_scale + qty_scale` decimal places; it is
+/// rescaled to `out_scale`, truncating any extra precision.
+///
+/// # Examples
+/// ```
+/// use financial_math::notional;
+///
+/// let price = 5_000_000_000_000u128;  // 50000.00000000
+/// let quantity = 50_000_000u128;      // 0.50000000
+/// let value = notional(price, 8, quantity, 8, 8).unwrap();
+/// assert_eq!(value, 2_500_000_000_000u128); // 25000.00000000
+/// ```
+pub fn notional(
+    price: u128,
+    price_scale: u32,
+    quantity: u128,
+    qty_scale: u32,
+    out_scale: u32
+) -> FinancialResult<u128> {
+    let product = safe_multiply(price, quantity)?;
+    let product_scale = price_scale
+        .checked_add(qty_scale)
+        .ok_or(FinancialError::InvalidScale)?;
+
+    Ok(FinancialValue::new(product, product_scale).to_scale(out_scale)?.value)
+}
+
+/// Calculate fixed-point reciprocal: 1 / value at the same scale
+///
+/// Used to invert a quote (e.g. BTC/USD to USD/BTC). The result is truncated
+/// to `scale` decimal places.
+///
+/// # Examples
+/// ```
+/// use financial_math::reciprocal;
+///
+/// let price = 200_000_000u128;  // 2.00000000
+/// let inverse = reciprocal(price, 8).unwrap();
+/// assert_eq!(inverse, 50_000_000u128); // 0.50000000
+/// ```
+pub fn reciprocal(value: u128, scale: u32) -> FinancialResult<u128> {
+    if value == 0 {
+        return Err(FinancialError::DivisionByZero);
+    }
+
+    let one_squared = scale
+        .checked_mul(2)
+        .and_then(|exponent| 10u128.checked_pow(exponent))
+        .ok_or(FinancialError::Overflow)?;
+
+    Ok(one_squared / value)
+}
+
 /// Add amounts with precision handling
 ///
 /// # Examples
@@ -145,6 +271,103 @@ pub fn add_amounts(amount1: u128, amount2: u128, _precision: u32) -> FinancialRe
     safe_add(amount1, amount2)
 }
 
+/// Signed difference between two values: returns (a >= b, |a - b|)
+///
+/// # Examples
+/// ```
+/// use financial_math::signed_difference;
+///
+/// let (is_positive, magnitude) = signed_difference(100_000_000, 110_000_000);
+/// assert!(!is_positive);
+/// assert_eq!(magnitude, 10_000_000u128); // 10.00000000
+/// ```
+#[inline(always)]
+pub fn signed_difference(a: u128, b: u128) -> (bool, u128) {
+    (a >= b, a.abs_diff(b))
+}
+
+/// Add two fixed-point values at different scales, returning the sum at `out_scale`
+///
+/// Both operands are rescaled to `out_scale` before adding; scaling down
+/// truncates the extra decimal places and scaling up returns `Overflow` if
+/// the rescaled operand does not fit in a u128.
+///
+/// # Examples
+/// ```
+/// use financial_math::add_cross_scale;
+///
+/// let price = 100_5000_0000u128;  // 100.50000000 (8 decimals)
+/// let adjustment = 2_5000u128;    // 2.5000 (4 decimals)
+/// let result = add_cross_scale(price, 8, adjustment, 4, 8).unwrap();
+/// assert_eq!(result, 103_0000_0000u128); // 103.00000000
+/// ```
+pub fn add_cross_scale(
+    a: u128,
+    a_scale: u32,
+    b: u128,
+    b_scale: u32,
+    out_scale: u32
+) -> FinancialResult<u128> {
+    let a = FinancialValue::new(a, a_scale).to_scale(out_scale)?;
+    let b = FinancialValue::new(b, b_scale).to_scale(out_scale)?;
+    safe_add(a.value, b.value)
+}
+
+/// Subtract two fixed-point values at different scales, returning a - b at `out_scale`
+///
+/// # Examples
+/// ```
+/// use financial_math::sub_cross_scale;
+///
+/// let price = 100_5000_0000u128;  // 100.50000000 (8 decimals)
+/// let adjustment = 2_5000u128;    // 2.5000 (4 decimals)
+/// let result = sub_cross_scale(price, 8, adjustment, 4, 8).unwrap();
+/// assert_eq!(result, 98_0000_0000u128); // 98.00000000
+/// ```
+pub fn sub_cross_scale(
+    a: u128,
+    a_scale: u32,
+    b: u128,
+    b_scale: u32,
+    out_scale: u32
+) -> FinancialResult<u128> {
+    let a = FinancialValue::new(a, a_scale).to_scale(out_scale)?;
+    let b = FinancialValue::new(b, b_scale).to_scale(out_scale)?;
+    safe_subtract(a.value, b.value)
+}
+
+/// Linear interpolation: y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+///
+/// Works for falling segments (`y1 < y0`), reversed x ranges, and
+/// extrapolation outside `[x0, x1]`; the interpolated offset is truncated
+/// toward zero. Returns `DivisionByZero` when `x1 == x0` and `Overflow` if the
+/// result would fall below zero or exceed `u128::MAX`.
+///
+/// # Examples
+/// ```
+/// use financial_math::lerp;
+///
+/// // Midpoint between (1.0, 100.0) and (3.0, 80.0)
+/// let y = lerp(1_0000_0000, 100_0000_0000, 3_0000_0000, 80_0000_0000, 2_0000_0000).unwrap();
+/// assert_eq!(y, 90_0000_0000u128); // 90.00000000
+/// ```
+pub fn lerp(x0: u128, y0: u128, x1: u128, y1: u128, x: u128) -> FinancialResult<u128> {
+    let (x_span_positive, x_span) = signed_difference(x1, x0);
+    if x_span == 0 {
+        return Err(FinancialError::DivisionByZero);
+    }
+
+    let (dy_positive, dy) = signed_difference(y1, y0);
+    let (dx_positive, dx) = signed_difference(x, x0);
+
+    let offset = safe_multiply(dy, dx)? / x_span;
+    if (dy_positive == dx_positive) == x_span_positive {
+        safe_add(y0, offset)
+    } else {
+        safe_subtract(y0, offset)
+    }
+}
+
 /// Calculate percentage change: ((new - old) / old) * 100
 ///
 /// # Examples
@@ -161,11 +384,7 @@ pub fn calculate_percentage_change(old_value: u128, new_value: u128) -> Financia
         return Err(FinancialError::DivisionByZero);
     }
 
-    let diff = if new_value > old_value {
-        new_value - old_value
-    } else {
-        old_value - new_value
-    };
+    let (_, diff) = signed_difference(new_value, old_value);
 
     // Calculate (diff / old_value) * 100 with 4 decimal places
     // To get percentage with 4 decimal places: (diff * 100 * 10000) / old_value
@@ -204,34 +423,89 @@ pub fn calculate_compound_change(old_value: u128, new_value: u128) -> FinancialR
     Ok(compound_change)
 }
 
-/// Convert decimal to basis points (multiply by 10000)
+/// Compound a series of period returns: prod(1 + r_i) - 1
+///
+/// Returns are given and returned in basis points and may be negative; a
+/// period return below -10000 bps (a loss of more than 100%) is rejected.
+/// The running growth factor is kept at 12 extra decimal places and the
+/// result is rounded to the nearest basis point.
+///
+/// # Examples
+/// ```
+/// use financial_math::compound_returns;
+///
+/// let total = compound_returns(&[1000, 1000]).unwrap(); // +10%, +10%
+/// assert_eq!(total, 2100); // +21%
+///
+/// let total = compound_returns(&[1000, -1000]).unwrap(); // +10%, -10%
+/// assert_eq!(total, -100); // -1%
+/// ```
+pub fn compound_returns(period_returns_bps: &[i128]) -> FinancialResult<i128> {
+    const BPS_ONE: i128 = 10_000;
+    const FACTOR_ONE: i128 = BPS_ONE * 1_000_000_000_000;
+
+    if period_returns_bps.is_empty() {
+        return Err(FinancialError::InvalidValue);
+    }
+
+    let mut factor = FACTOR_ONE;
+    for &period_return in period_returns_bps {
+        let growth = BPS_ONE
+            .checked_add(period_return)
+            .ok_or(FinancialError::Overflow)?;
+        if growth < 0 {
+            return Err(FinancialError::InvalidValue);
+        }
+
+        factor = factor
+            .checked_mul(growth)
+            .ok_or(FinancialError::Overflow)?
+            / BPS_ONE;
+    }
+
+    let change = factor - FACTOR_ONE;
+    let divisor = FACTOR_ONE / BPS_ONE;
+    let half = divisor / 2;
+    Ok(if change >= 0 { (change + half) / divisor } else { (change - half) / divisor })
+}
+
+/// Convert a percentage to basis points
+///
+/// The input is a percentage at `PERCENTAGE_SCALE` (4 decimal places), so
+/// 1.5000% is `1_5000` and converts to 150 bps. Precision finer than one
+/// basis point (the last two digits) is truncated, which makes this the exact
+/// inverse of `from_basis_points` for every percentage that is a whole number
+/// of basis points.
 ///
 /// # Examples
 /// ```
 /// use financial_math::to_basis_points;
 ///
-/// let decimal = 1_5000u128;  // 1.5000 (4 decimal places)
-/// let bps = to_basis_points(decimal);
-/// assert_eq!(bps, 15000u128); // 15000 basis points = 1.5%
+/// let percentage = 1_5000u128;  // 1.5000% (4 decimal places)
+/// let bps = to_basis_points(percentage);
+/// assert_eq!(bps, 150u128); // 150 basis points = 1.5%
 /// ```
 #[inline(always)]
-pub fn to_basis_points(decimal: u128) -> u128 {
-    decimal / 100u128  // Since decimal has 4 decimal places, divide by 100 to get basis points
+pub fn to_basis_points(percentage: u128) -> u128 {
+    percentage / 100u128  // 1 bps = 0.01% = 100 at 4 decimal places
 }
 
-/// Convert basis points to decimal
+/// Convert basis points to a percentage at `PERCENTAGE_SCALE` (4 decimal places)
+///
+/// Exact inverse of `to_basis_points`; saturates at `u128::MAX` for inputs
+/// above `u128::MAX / 100`.
 ///
 /// # Examples
 /// ```
 /// use financial_math::from_basis_points;
 ///
-/// let bps = 15000u128;  // 15000 basis points = 1.5%
-/// let decimal = from_basis_points(bps);
-/// assert_eq!(decimal, 1_5000u128); // 1.5000 (4 decimal places)
+/// let bps = 150u128;  // 150 basis points = 1.5%
+/// let percentage = from_basis_points(bps);
+/// assert_eq!(percentage, 1_5000u128); // 1.5000% (4 decimal places)
 /// ```
 #[inline(always)]
 pub fn from_basis_points(basis_points: u128) -> u128 {
-    basis_points * 100u128  // Convert to 4 decimal places
+    basis_points.saturating_mul(100u128)  // Convert to 4 decimal places
 }
 
 /// Financial rounding to specified decimal places
@@ -245,30 +519,57 @@ pub fn from_basis_points(basis_points: u128) -> u128 {
 /// assert_eq!(rounded, 123_4600_0000u128); // 123.46
 /// ```
 pub fn financial_round(value: u128, current_scale: u32, target_decimals: u32) -> u128 {
+    financial_round_mode(value, current_scale, target_decimals, RoundMode::HalfEven)
+}
+
+/// Rounding to specified decimal places with an explicit rounding mode
+///
+/// `RoundMode::Nearest` behaves like `RoundMode::HalfUp`. When rounding up
+/// would exceed `u128::MAX`, the value is rounded down instead, to the largest
+/// representable multiple of the target precision.
+///
+/// # Examples
+/// ```
+/// use financial_math::{financial_round_mode, RoundMode};
+///
+/// let value = 2_5000_0000u128;  // 2.50000000
+/// assert_eq!(financial_round_mode(value, 8, 0, RoundMode::HalfEven), 2_0000_0000u128); // 2
+/// assert_eq!(financial_round_mode(value, 8, 0, RoundMode::HalfUp), 3_0000_0000u128); // 3
+/// ```
+pub fn financial_round_mode(
+    value: u128,
+    current_scale: u32,
+    target_decimals: u32,
+    mode: RoundMode
+) -> u128 {
     if target_decimals >= current_scale {
         return value;
     }
 
     let divisor = 10u128.pow(current_scale - target_decimals);
     let remainder = value % divisor;
+    if remainder == 0 {
+        return value;
+    }
 
-    // Round half up (banker's rounding)
-    let threshold = divisor / 2;
-    let rounded_value = if remainder > threshold {
-        value + (divisor - remainder)
-    } else if remainder == threshold {
-        // Banker's rounding: round to even
-        let base = value - remainder;
-        if (base / divisor) % 2 == 0 {
-            base
-        } else {
-            base + divisor
+    let base = value - remainder;
+    let round_up = match mode {
+        RoundMode::Down => false,
+        RoundMode::Up => true,
+        RoundMode::Nearest | RoundMode::HalfUp => remainder >= divisor / 2,
+        RoundMode::HalfEven => {
+            let threshold = divisor / 2;
+            // Exact halves round to the even neighbour (banker's rounding)
+            remainder > threshold
+                || (remainder == threshold && !(base / divisor).is_multiple_of(2))
         }
-    } else {
-        value - remainder
     };
 
-    rounded_value
+    if round_up {
+        base.checked_add(divisor).unwrap_or(base)
+    } else {
+        base
+    }
 }
 
 /// Absolute value for u128 (no-op since unsigned)
@@ -307,6 +608,26 @@ mod tests {
         // Division
         assert_eq!(safe_divide(100, 2).unwrap(), 50);
         assert!(safe_divide(100, 0).is_err()); // Division by zero
+
+        // Division with remainder
+        assert_eq!(divide_with_remainder(100, 3).unwrap(), (33, 1));
+        assert_eq!(divide_with_remainder(100, 4).unwrap(), (25, 0));
+        assert_eq!(divide_with_remainder(100, 0), Err(FinancialError::DivisionByZero));
+
+        // Saturating twins match the checked versions until they would overflow
+        for (a, b) in [(100, 50), (0, 0), (u128::MAX - 1, 1)] {
+            assert_eq!(sat_add(a, b), safe_add(a, b).unwrap());
+        }
+        for (a, b) in [(100, 2), (0, u128::MAX), (u128::MAX, 1)] {
+            assert_eq!(sat_multiply(a, b), safe_multiply(a, b).unwrap());
+        }
+        assert_eq!(sat_add(u128::MAX, 1), u128::MAX);
+        assert_eq!(sat_multiply(u128::MAX / 2 + 1, 2), u128::MAX);
+
+        // Slice sum
+        assert_eq!(safe_sum(&[100, 50, 25]).unwrap(), 175);
+        assert_eq!(safe_sum(&[]).unwrap(), 0);
+        assert_eq!(safe_sum(&[u128::MAX / 2, u128::MAX / 2, 2]), Err(FinancialError::Overflow));
     }
 
     #[test]
@@ -325,6 +646,127 @@ mod tests {
         assert_eq!(divide_quantities(100_000_000, 4_000_000).unwrap(), 50_000_000_000_000);
     }
 
+    #[test]
+    fn test_mid_price_any_order() {
+        let bid = 100_000_000u128;  // 100.00000000
+        let ask = 101_000_000u128;  // 101.00000000
+
+        // Normal and crossed books yield the same midpoint
+        assert_eq!(calculate_mid_price(bid, ask), 100_500_000u128);
+        assert_eq!(calculate_mid_price(ask, bid), 100_500_000u128);
+
+        // Locked book
+        assert_eq!(calculate_mid_price(bid, bid), bid);
+
+        // Odd sums truncate like (bid + ask) / 2
+        assert_eq!(calculate_mid_price(3, 4), 3);
+        assert_eq!(calculate_mid_price(3, 5), 4);
+
+        // Values whose naive sum would overflow
+        assert_eq!(calculate_mid_price(u128::MAX, u128::MAX), u128::MAX);
+        assert_eq!(calculate_mid_price(u128::MAX, u128::MAX - 2), u128::MAX - 1);
+    }
+
+    #[test]
+    fn test_lerp() {
+        let (x0, x1) = (1_0000_0000u128, 3_0000_0000u128);
+
+        // Rising segment: endpoints and midpoint
+        assert_eq!(lerp(x0, 100_0000_0000, x1, 120_0000_0000, x0).unwrap(), 100_0000_0000);
+        assert_eq!(lerp(x0, 100_0000_0000, x1, 120_0000_0000, x1).unwrap(), 120_0000_0000);
+        assert_eq!(lerp(x0, 100_0000_0000, x1, 120_0000_0000, 2_0000_0000).unwrap(), 110_0000_0000);
+
+        // Falling segment: endpoints and midpoint
+        assert_eq!(lerp(x0, 100_0000_0000, x1, 80_0000_0000, x0).unwrap(), 100_0000_0000);
+        assert_eq!(lerp(x0, 100_0000_0000, x1, 80_0000_0000, x1).unwrap(), 80_0000_0000);
+        assert_eq!(lerp(x0, 100_0000_0000, x1, 80_0000_0000, 2_0000_0000).unwrap(), 90_0000_0000);
+
+        // Reversed x range and extrapolation
+        assert_eq!(lerp(x1, 80_0000_0000, x0, 100_0000_0000, 2_0000_0000).unwrap(), 90_0000_0000);
+        assert_eq!(lerp(x0, 100_0000_0000, x1, 80_0000_0000, 0).unwrap(), 110_0000_0000);
+
+        // Flat segment
+        assert_eq!(lerp(x0, 50, x1, 50, 2_0000_0000).unwrap(), 50);
+
+        // Degenerate x range and negative results
+        assert_eq!(lerp(x0, 100, x0, 200, x0), Err(FinancialError::DivisionByZero));
+        assert!(lerp(0, 10, 1, 0, 2).is_err());
+    }
+
+    #[test]
+    fn test_signed_difference() {
+        assert_eq!(signed_difference(110, 100), (true, 10));
+        assert_eq!(signed_difference(100, 110), (false, 10));
+        assert_eq!(signed_difference(100, 100), (true, 0));
+        assert_eq!(signed_difference(u128::MAX, 0), (true, u128::MAX));
+    }
+
+    #[test]
+    fn test_notional() {
+        // 50000.00 x 0.5 = 25000.00
+        let price = 5_000_000_000_000u128;  // 50000.00000000
+        let quantity = 50_000_000u128;      // 0.50000000
+        assert_eq!(notional(price, 8, quantity, 8, 8).unwrap(), 2_500_000_000_000u128);
+
+        // Mixed input scales
+        assert_eq!(notional(5_000_000, 2, 50_000_000, 8, 8).unwrap(), 2_500_000_000_000u128);
+        assert_eq!(notional(5_000_000, 2, 50_000_000, 8, 2).unwrap(), 2_500_000u128);
+
+        // Output scale above the product scale
+        assert_eq!(notional(5_000_000, 2, 5, 1, 4).unwrap(), 250_000_000u128);
+
+        // Overflow is reported
+        assert!(notional(u128::MAX, 8, 2, 8, 8).is_err());
+
+        // Large scale gaps return instead of panicking
+        assert_eq!(notional(1, 20, 1, 20, 0).unwrap(), 0);
+        assert_eq!(notional(1, 0, 1, 0, 40), Err(FinancialError::Overflow));
+    }
+
+    #[test]
+    fn test_reciprocal() {
+        assert_eq!(reciprocal(200_000_000, 8).unwrap(), 50_000_000);  // 1 / 2.0 = 0.5
+        assert_eq!(reciprocal(400_000_000, 8).unwrap(), 25_000_000);  // 1 / 4.0 = 0.25
+
+        // Round trip returns the original value within truncation error
+        let price = 6_543_210_000_000u128; // 65432.10000000
+        let round_trip = reciprocal(reciprocal(price, 8).unwrap(), 8).unwrap();
+        let relative_error = price.abs_diff(round_trip) * 10_000 / price;
+        assert!(relative_error < 10); // within 0.1%
+
+        let price = 123_456_789u128; // 1.23456789
+        let round_trip = reciprocal(reciprocal(price, 8).unwrap(), 8).unwrap();
+        assert!(price.abs_diff(round_trip) <= 1);
+
+        // Division by zero and oversized scales should fail
+        assert_eq!(reciprocal(0, 8), Err(FinancialError::DivisionByZero));
+        assert!(reciprocal(1, 20).is_err());
+    }
+
+    #[test]
+    fn test_cross_scale_arithmetic() {
+        let price = 100_5000_0000u128;  // 100.50000000 (scale 8)
+        let adjustment = 2_5000u128;    // 2.5000 (scale 4)
+
+        assert_eq!(add_cross_scale(price, 8, adjustment, 4, 8).unwrap(), 103_0000_0000);
+        assert_eq!(add_cross_scale(adjustment, 4, price, 8, 8).unwrap(), 103_0000_0000);
+        assert_eq!(sub_cross_scale(price, 8, adjustment, 4, 8).unwrap(), 98_0000_0000);
+
+        // Lower output scale truncates both operands
+        assert_eq!(add_cross_scale(100_1234_5678, 8, 1_2345, 4, 2).unwrap(), 10_135); // 101.35
+
+        // Underflow and overflow are reported
+        assert!(sub_cross_scale(adjustment, 4, price, 8, 8).is_err());
+        assert!(add_cross_scale(u128::MAX, 8, 1, 4, 8).is_err());
+        assert!(add_cross_scale(u128::MAX, 4, 1, 4, 8).is_err());
+
+        // Scale gaps wider than 10^38 return instead of panicking
+        assert_eq!(add_cross_scale(1, 0, 1, 0, 39), Err(FinancialError::Overflow));
+        assert_eq!(sub_cross_scale(1, 0, 0, 0, 40), Err(FinancialError::Overflow));
+        assert_eq!(sub_cross_scale(1, 40, 0, 0, 0).unwrap(), 0);
+        assert_eq!(add_cross_scale(5, 0, 1, 45, 0).unwrap(), 5);
+    }
+
     #[test]
     fn test_percentage_calculations() {
         let old_value = 100_000_000u128;  // 100.00000000
@@ -339,14 +781,48 @@ mod tests {
         assert_eq!(compound, 1000u128); // 10.0000% (actual result)
     }
 
+    #[test]
+    fn test_compound_returns() {
+        // Two +10% periods compound to +21%
+        assert_eq!(compound_returns(&[1000, 1000]).unwrap(), 2100);
+
+        // +10% then -10% nets slightly negative
+        assert_eq!(compound_returns(&[1000, -1000]).unwrap(), -100);
+
+        // Single period passes through, total loss floors at -100%
+        assert_eq!(compound_returns(&[250]).unwrap(), 250);
+        assert_eq!(compound_returns(&[5000, -10000]).unwrap(), -10000);
+
+        // Three +1% periods: 1.01^3 - 1 = 3.0301%
+        assert_eq!(compound_returns(&[100, 100, 100]).unwrap(), 303);
+
+        // Empty input and losses beyond -100% should fail
+        assert!(compound_returns(&[]).is_err());
+        assert!(compound_returns(&[-10001]).is_err());
+    }
+
     #[test]
     fn test_basis_points() {
-        let decimal = 1_5000u128;  // 1.5000
-        let bps = to_basis_points(decimal);
-        assert_eq!(bps, 150u128); // 150 basis points (1.5000 / 100 = 0.015 = 150 bps)
+        let percentage = 1_5000u128;  // 1.5000%
+        let bps = to_basis_points(percentage);
+        assert_eq!(bps, 150u128); // 150 basis points (1.5% = 150 bps)
 
-        let back_to_decimal = from_basis_points(bps);
-        assert_eq!(back_to_decimal, 15_000u128); // 150 * 100 = 15000
+        let back_to_percentage = from_basis_points(bps);
+        assert_eq!(back_to_percentage, 1_5000u128); // 150 * 100 = 1.5000%
+
+        // Round trip is exact for whole basis points
+        for percentage in [0u128, 100, 1_0000, 1_5000, 100_0000, 12_3400, u128::MAX / 100 * 100] {
+            assert_eq!(from_basis_points(to_basis_points(percentage)), percentage);
+        }
+        for bps in [0u128, 1, 150, 10_000, u128::MAX / 100] {
+            assert_eq!(to_basis_points(from_basis_points(bps)), bps);
+        }
+
+        // Sub-basis-point precision is truncated
+        assert_eq!(to_basis_points(1_5099), 150);
+
+        // Out-of-range basis points saturate instead of overflowing
+        assert_eq!(from_basis_points(u128::MAX), u128::MAX);
     }
 
     #[test]
@@ -362,6 +838,66 @@ mod tests {
         assert_eq!(rounded4, 123_4568_0000u128); // 123.4568 (rounded up from 123.45678901)
     }
 
+    #[test]
+    fn test_financial_rounding_modes() {
+        let two_and_half = 2_5000_0000u128;    // 2.50000000
+        let three_and_half = 3_5000_0000u128;  // 3.50000000
+
+        // Half-even rounds exact halves to the even neighbour
+        assert_eq!(financial_round_mode(two_and_half, 8, 0, RoundMode::HalfEven), 2_0000_0000);
+        assert_eq!(financial_round_mode(three_and_half, 8, 0, RoundMode::HalfEven), 4_0000_0000);
+
+        // Half-up always rounds exact halves up
+        assert_eq!(financial_round_mode(two_and_half, 8, 0, RoundMode::HalfUp), 3_0000_0000);
+        assert_eq!(financial_round_mode(three_and_half, 8, 0, RoundMode::HalfUp), 4_0000_0000);
+        assert_eq!(financial_round_mode(two_and_half, 8, 0, RoundMode::Nearest), 3_0000_0000);
+
+        // Directed modes ignore the midpoint
+        let value = 2_1000_0000u128;  // 2.10000000
+        assert_eq!(financial_round_mode(value, 8, 0, RoundMode::Down), 2_0000_0000);
+        assert_eq!(financial_round_mode(value, 8, 0, RoundMode::Up), 3_0000_0000);
+        assert_eq!(financial_round_mode(2_0000_0000, 8, 0, RoundMode::Up), 2_0000_0000);
+
+        // Default function keeps half-even behaviour
+        assert_eq!(financial_round(two_and_half, 8, 0), 2_0000_0000);
+
+        // Target at or above current scale returns the value unchanged
+        assert_eq!(financial_round_mode(value + 1, 8, 8, RoundMode::Up), value + 1);
+        assert_eq!(financial_round_mode(value + 1, 8, 10, RoundMode::Down), value + 1);
+
+        // Rounding up past u128::MAX falls back to the largest whole unit
+        let largest = u128::MAX - u128::MAX % 1_0000_0000;
+        assert_eq!(financial_round_mode(u128::MAX, 8, 0, RoundMode::Up), largest);
+        assert_eq!(financial_round_mode(u128::MAX, 8, 0, RoundMode::HalfUp), largest);
+        assert_eq!(financial_round_mode(u128::MAX, 8, 0, RoundMode::HalfEven), largest);
+    }
+
+    #[test]
+    fn test_financial_round_half_even_boundaries() {
+        // Exact halves go to the even neighbour: 0.5->0, 1.5->2, 2.5->2, 3.5->4
+        assert_eq!(financial_round(5000_0000, 8, 0), 0);
+        assert_eq!(financial_round(1_5000_0000, 8, 0), 2_0000_0000);
+        assert_eq!(financial_round(2_5000_0000, 8, 0), 2_0000_0000);
+        assert_eq!(financial_round(3_5000_0000, 8, 0), 4_0000_0000);
+
+        // One unit either side of the half always follows the nearer neighbour
+        for whole in 0..4u128 {
+            let half = whole * 1_0000_0000 + 5000_0000;
+            assert_eq!(financial_round(half - 1, 8, 0), whole * 1_0000_0000);
+            assert_eq!(financial_round(half + 1, 8, 0), (whole + 1) * 1_0000_0000);
+        }
+
+        // Same behaviour at a non-zero target precision: 1.005->1.00, 1.015->1.02
+        assert_eq!(financial_round(1_0050_0000, 8, 2), 1_0000_0000);
+        assert_eq!(financial_round(1_0150_0000, 8, 2), 1_0200_0000);
+        assert_eq!(financial_round(1_0250_0000, 8, 2), 1_0200_0000);
+
+        // Single-digit divisor (scale 1 -> 0)
+        assert_eq!(financial_round(5, 1, 0), 0);
+        assert_eq!(financial_round(15, 1, 0), 20);
+        assert_eq!(financial_round(25, 1, 0), 20);
+    }
+
     #[test]
     fn test_edge_cases() {
         // Zero operations
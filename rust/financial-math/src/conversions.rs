@@ -4,7 +4,7 @@
 //! This module handles the critical conversions that maintain precision
 //! while interfacing with external systems that use floating-point.
 
-use crate::{FinancialResult, FinancialError, Scale, PRICE_SCALE, QUANTITY_SCALE};
+use crate::{FinancialResult, FinancialError, RoundMode, financial_round, Scale, PRICE_SCALE, QUANTITY_SCALE};
 
 /// Convert a floating-point price to u128 fixed-point representation
 ///
@@ -43,6 +43,69 @@ pub fn int_to_price(price_int: u128) -> f64 {
     price_int as f64 / multiplier
 }
 
+/// Convert u128 fixed-point price to floating-point rounded to `decimals` places
+///
+/// The fixed-point value is rounded with `financial_round` (banker's rounding)
+/// before conversion, so float artifacts from extra fixed-point digits do not
+/// reach display code.
+///
+/// # Examples
+/// ```
+/// use financial_math::{int_to_price, int_to_price_rounded};
+///
+/// let fixed_point = 123_4567_8899u128;  // 123.45678899
+/// assert_eq!(int_to_price(fixed_point), 123.45678899);
+/// assert_eq!(int_to_price_rounded(fixed_point, 6), 123.456789);
+/// ```
+pub fn int_to_price_rounded(price_int: u128, decimals: u32) -> f64 {
+    let scale = PRICE_SCALE.value();
+    if decimals >= scale {
+        return int_to_price(price_int);
+    }
+
+    let rounded = financial_round(price_int, scale, decimals);
+    // Divide out the trailing zeros first so the final division is exact-rounded
+    let units = rounded / 10u128.pow(scale - decimals);
+    units as f64 / 10u128.pow(decimals) as f64
+}
+
+/// Convert a slice of floating-point prices to u128 fixed-point representation
+///
+/// Stops at the first invalid price and reports its position.
+///
+/// # Examples
+/// ```
+/// use financial_math::{prices_to_int, FinancialError};
+///
+/// let fixed = prices_to_int(&[123.456789, 100.0]).unwrap();
+/// assert_eq!(fixed, vec![12345678900u128, 10000000000u128]);
+///
+/// let invalid = prices_to_int(&[100.0, f64::NAN]);
+/// assert_eq!(invalid, Err(FinancialError::InvalidValueAt(1)));
+/// ```
+pub fn prices_to_int(prices: &[f64]) -> FinancialResult<Vec<u128>> {
+    prices
+        .iter()
+        .enumerate()
+        .map(|(index, &price)| {
+            price_to_int(price).map_err(|_| FinancialError::InvalidValueAt(index))
+        })
+        .collect()
+}
+
+/// Convert a slice of u128 fixed-point prices back to floating-point
+///
+/// # Examples
+/// ```
+/// use financial_math::ints_to_prices;
+///
+/// let prices = ints_to_prices(&[12345678900u128, 10000000000u128]);
+/// assert_eq!(prices, vec![123.456789, 100.0]);
+/// ```
+pub fn ints_to_prices(values: &[u128]) -> Vec<f64> {
+    values.iter().map(|&value| int_to_price(value)).collect()
+}
+
 /// Convert a floating-point quantity to u128 fixed-point representation
 ///
 /// # Examples
@@ -80,6 +143,112 @@ pub fn int_to_quantity(quantity_int: u128) -> f64 {
     quantity_int as f64 / multiplier
 }
 
+/// Convert a floating-point quantity to u128 fixed-point at a custom scale
+///
+/// For instruments whose quantities carry more than 8 decimals (e.g. 18-decimal
+/// tokens). Scales above 38 are rejected since `10^scale` would not fit in a
+/// u128, and results beyond `u128::MAX` return `Overflow`.
+///
+/// f64 only holds about 15-17 significant decimal digits, so at high scales
+/// the low-order digits are float noise. Amounts that must be exact should be
+/// parsed from their decimal string with `decimal_str_to_int`.
+///
+/// # Examples
+/// ```
+/// use financial_math::quantity_to_int_scaled;
+///
+/// let quantity = 1.5;  // 1.5 tokens
+/// let fixed_point = quantity_to_int_scaled(quantity, 18).unwrap();
+/// assert_eq!(fixed_point, 1_500_000_000_000_000_000u128);
+/// ```
+pub fn quantity_to_int_scaled(quantity: f64, scale: u32) -> FinancialResult<u128> {
+    if scale > 38 {
+        return Err(FinancialError::InvalidScale);
+    }
+    if !quantity.is_finite() || quantity < 0.0 {
+        return Err(FinancialError::InvalidValue);
+    }
+
+    let scaled = (quantity * 10u128.pow(scale) as f64).round();
+    // u128::MAX as f64 rounds up to 2^128, which itself does not fit
+    if scaled >= u128::MAX as f64 {
+        return Err(FinancialError::Overflow);
+    }
+
+    Ok(scaled as u128)
+}
+
+/// Convert u128 fixed-point quantity at a custom scale back to floating-point
+///
+/// # Examples
+/// ```
+/// use financial_math::int_to_quantity_scaled;
+///
+/// let fixed_point = 1_500_000_000_000_000_000u128;  // 1.5 at 18 decimals
+/// assert_eq!(int_to_quantity_scaled(fixed_point, 18).unwrap(), 1.5);
+/// ```
+pub fn int_to_quantity_scaled(quantity_int: u128, scale: u32) -> FinancialResult<f64> {
+    if scale > 38 {
+        return Err(FinancialError::InvalidScale);
+    }
+
+    Ok(quantity_int as f64 / 10u128.pow(scale) as f64)
+}
+
+/// Parse a decimal string into u128 fixed-point without going through f64
+///
+/// Accepts plain decimal notation (`"123"`, `"0.1"`, `".5"`, `"12."`), so
+/// exchange prices convert exactly. Fractional digits beyond `scale` are only
+/// accepted when they are zeros; anything else would lose precision and
+/// returns `InvalidValue`. A leading `-` returns `NegativeValue`.
+///
+/// # Examples
+/// ```
+/// use financial_math::decimal_str_to_int;
+///
+/// assert_eq!(decimal_str_to_int("0.1", 8).unwrap(), 10_000_000u128);
+/// assert_eq!(decimal_str_to_int("1234567890.12345678", 8).unwrap(), 123456789012345678u128);
+/// assert!(decimal_str_to_int("0.123456789", 8).is_err()); // too many decimals
+/// ```
+pub fn decimal_str_to_int(value: &str, scale: u32) -> FinancialResult<u128> {
+    if scale > 38 {
+        return Err(FinancialError::InvalidScale);
+    }
+    if value.starts_with('-') {
+        return Err(FinancialError::NegativeValue);
+    }
+
+    let (integer_part, fraction_part) = match value.split_once('.') {
+        Some((integer_part, fraction_part)) => (integer_part, fraction_part),
+        None => (value, ""),
+    };
+
+    let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if (integer_part.is_empty() && fraction_part.is_empty())
+        || !is_digits(integer_part)
+        || !is_digits(fraction_part)
+    {
+        return Err(FinancialError::InvalidValue);
+    }
+
+    let scale = scale as usize;
+    let (kept, excess) = fraction_part.split_at(fraction_part.len().min(scale));
+    if excess.bytes().any(|b| b != b'0') {
+        return Err(FinancialError::InvalidValue);
+    }
+
+    let mut result = 0u128;
+    let padding = std::iter::repeat_n(b'0', scale - kept.len());
+    for digit in integer_part.bytes().chain(kept.bytes()).chain(padding) {
+        result = result
+            .checked_mul(10)
+            .and_then(|r| r.checked_add((digit - b'0') as u128))
+            .ok_or(FinancialError::Overflow)?;
+    }
+
+    Ok(result)
+}
+
 /// Convert percentage to fixed-point representation (4 decimal places)
 ///
 /// # Examples
@@ -128,14 +297,52 @@ pub fn int_to_percentage(percentage_int: u128) -> f64 {
 /// assert_eq!(fixed_point, 123456789u128);
 /// ```
 pub fn float_to_fixed(value: f64, scale: Scale) -> FinancialResult<u128> {
+    float_to_fixed_mode(value, scale, RoundMode::Nearest)
+}
+
+/// Conversion from floating-point to fixed-point with an explicit rounding mode
+///
+/// `RoundMode::Down` never overstates the value, which makes it the safe
+/// choice for converting order quantities. The scaled value is computed in
+/// f64, so decimals that are not exactly representable (e.g. 0.29) land a few
+/// ULPs off the intended integer; such products are snapped to that integer
+/// before directed rounding so `Down`/`Up` do not move them by a whole unit.
+///
+/// # Examples
+/// ```
+/// use financial_math::{float_to_fixed_mode, RoundMode, Scale};
+///
+/// let value = 1.999999995;
+/// assert_eq!(float_to_fixed_mode(value, Scale::Custom(8), RoundMode::Nearest).unwrap(), 200000000u128);
+/// assert_eq!(float_to_fixed_mode(value, Scale::Custom(8), RoundMode::Down).unwrap(), 199999999u128);
+/// assert_eq!(float_to_fixed_mode(value, Scale::Custom(8), RoundMode::Up).unwrap(), 200000000u128);
+/// ```
+pub fn float_to_fixed_mode(value: f64, scale: Scale, mode: RoundMode) -> FinancialResult<u128> {
     if !value.is_finite() || value < 0.0 {
         return Err(FinancialError::InvalidValue);
     }
 
-    let multiplier = scale.multiplier();
-    let scaled = (value * multiplier as f64).round() as u128;
+    let mut scaled = value * scale.multiplier() as f64;
 
-    Ok(scaled)
+    // Snap products within a few ULPs of an integer to that integer
+    let nearest = scaled.round();
+    if (scaled - nearest).abs() <= nearest * 4.0 * f64::EPSILON {
+        scaled = nearest;
+    }
+
+    let rounded = match mode {
+        RoundMode::Nearest | RoundMode::HalfUp => scaled.round(),
+        RoundMode::HalfEven => scaled.round_ties_even(),
+        RoundMode::Down => scaled.trunc(),
+        RoundMode::Up => scaled.ceil(),
+    };
+
+    // u128::MAX as f64 rounds up to 2^128, which itself does not fit
+    if rounded >= u128::MAX as f64 {
+        return Err(FinancialError::Overflow);
+    }
+
+    Ok(rounded as u128)
 }
 
 /// Generic conversion from fixed-point to floating-point with custom scale
@@ -211,6 +418,45 @@ mod tests {
         assert!((back_to_float - price).abs() < 1e-10); // Very small difference due to rounding
     }
 
+    #[test]
+    fn test_rounded_price_conversion() {
+        // 123.45679 stored with a trailing fixed-point artifact: the raw
+        // conversion is a hair off the clean decimal, the rounded one is exact
+        let fixed = 123_4567_8999u128;  // 123.45678999
+        let raw = int_to_price(fixed);
+        assert_ne!(raw, 123.45679);
+        assert!((raw - 123.45679).abs() < 1e-7);
+        assert_eq!(int_to_price_rounded(fixed, 6), 123.45679);
+        assert_eq!(int_to_price_rounded(fixed, 2), 123.46);
+
+        // Exact halves use banker's rounding
+        assert_eq!(int_to_price_rounded(1_2500_0000, 1), 1.2);
+        assert_eq!(int_to_price_rounded(1_3500_0000, 1), 1.4);
+
+        // At or above the price scale the raw conversion is returned
+        assert_eq!(int_to_price_rounded(fixed, 8), int_to_price(fixed));
+        assert_eq!(int_to_price_rounded(fixed, 12), int_to_price(fixed));
+    }
+
+    #[test]
+    fn test_batch_price_conversions() {
+        let prices = vec![123.456789, 100.0, 0.00000001];
+        let fixed = prices_to_int(&prices).unwrap();
+        assert_eq!(fixed, vec![12345678900u128, 10000000000u128, 1u128]);
+
+        let back_to_float = ints_to_prices(&fixed);
+        for (original, converted) in prices.iter().zip(back_to_float.iter()) {
+            assert!((original - converted).abs() < 1e-10);
+        }
+
+        // First invalid price is reported with its index
+        let invalid = prices_to_int(&[100.0, 101.0, f64::NAN, -1.0]);
+        assert_eq!(invalid, Err(FinancialError::InvalidValueAt(2)));
+
+        // Empty input is a valid, empty batch
+        assert!(prices_to_int(&[]).unwrap().is_empty());
+    }
+
     #[test]
     fn test_quantity_conversions() {
         let quantity = 100.12345678;
@@ -221,6 +467,68 @@ mod tests {
         assert!((back_to_float - quantity).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_scaled_quantity_conversions() {
+        // 18-decimal token amount
+        let quantity = 2.25;
+        let fixed = quantity_to_int_scaled(quantity, 18).unwrap();
+        assert_eq!(fixed, 2_250_000_000_000_000_000u128);
+        assert_eq!(int_to_quantity_scaled(fixed, 18).unwrap(), quantity);
+
+        // Digits beyond f64 precision are approximate, not exact
+        let fixed = quantity_to_int_scaled(1234.5, 18).unwrap();
+        assert_ne!(fixed, 1_234_500_000_000_000_000_000u128);
+        assert!(fixed.abs_diff(1_234_500_000_000_000_000_000) < 1_000_000);
+        let fixed = quantity_to_int_scaled(0.123456789, 18).unwrap();
+        assert!(fixed.abs_diff(123_456_789_000_000_000) < 1_000);
+
+        // Matches the fixed 8-decimal conversion at scale 8
+        assert_eq!(quantity_to_int_scaled(100.12345678, 8).unwrap(), quantity_to_int(100.12345678).unwrap());
+
+        // Scale and overflow guards
+        assert_eq!(quantity_to_int_scaled(1.0, 39), Err(FinancialError::InvalidScale));
+        assert_eq!(int_to_quantity_scaled(1, 39), Err(FinancialError::InvalidScale));
+        assert_eq!(quantity_to_int_scaled(1e21, 18), Err(FinancialError::Overflow));
+        assert!(quantity_to_int_scaled(1e20, 18).is_ok());
+        assert!(quantity_to_int_scaled(-1.0, 18).is_err());
+        assert!(quantity_to_int_scaled(f64::NAN, 18).is_err());
+    }
+
+    #[test]
+    fn test_decimal_str_to_int() {
+        // Exact where the f64 path is not
+        assert_eq!(decimal_str_to_int("0.1", 8).unwrap(), 10_000_000);
+        let exact = decimal_str_to_int("1234567890.12345678", 8).unwrap();
+        assert_eq!(exact, 123_456_789_012_345_678);
+        // Parse the same text as f64 to show the precision it loses
+        let approximate: f64 = "1234567890.12345678".parse().unwrap();
+        assert_ne!(price_to_int(approximate).unwrap(), exact);
+
+        // Accepted forms
+        assert_eq!(decimal_str_to_int("123", 8).unwrap(), 123_0000_0000);
+        assert_eq!(decimal_str_to_int(".5", 8).unwrap(), 5000_0000);
+        assert_eq!(decimal_str_to_int("12.", 2).unwrap(), 1200);
+        assert_eq!(decimal_str_to_int("1.50000000000", 8).unwrap(), 1_5000_0000);
+        assert_eq!(decimal_str_to_int("7", 0).unwrap(), 7);
+
+        // 18-decimal token amounts
+        assert_eq!(
+            decimal_str_to_int("1234.567890123456789", 18).unwrap(),
+            1_234_567_890_123_456_789_000u128
+        );
+
+        // Rejected forms
+        assert_eq!(decimal_str_to_int("0.123456789", 8), Err(FinancialError::InvalidValue));
+        assert_eq!(decimal_str_to_int("-1.0", 8), Err(FinancialError::NegativeValue));
+        assert_eq!(decimal_str_to_int("1e5", 8), Err(FinancialError::InvalidValue));
+        assert_eq!(decimal_str_to_int("", 8), Err(FinancialError::InvalidValue));
+        assert_eq!(decimal_str_to_int(".", 8), Err(FinancialError::InvalidValue));
+        assert_eq!(decimal_str_to_int("1.2.3", 8), Err(FinancialError::InvalidValue));
+        assert_eq!(decimal_str_to_int(" 1", 8), Err(FinancialError::InvalidValue));
+        assert_eq!(decimal_str_to_int("1", 39), Err(FinancialError::InvalidScale));
+        assert_eq!(decimal_str_to_int("10000000000000000000000000000000", 8), Err(FinancialError::Overflow));
+    }
+
     #[test]
     fn test_percentage_conversions() {
         let percentage = 12.3456;
@@ -261,6 +569,46 @@ mod tests {
         assert!((back_to_float - value).abs() < 1e-6);
     }
 
+    #[test]
+    fn test_rounding_mode_conversions() {
+        let value = 1.999999995;
+        let scale = Scale::Custom(8);
+
+        assert_eq!(float_to_fixed_mode(value, scale, RoundMode::Nearest).unwrap(), 200000000u128);
+        assert_eq!(float_to_fixed_mode(value, scale, RoundMode::Down).unwrap(), 199999999u128);
+        assert_eq!(float_to_fixed_mode(value, scale, RoundMode::Up).unwrap(), 200000000u128);
+
+        // Below the midpoint only Up moves to the next unit
+        let value = 1.999999994;
+        assert_eq!(float_to_fixed_mode(value, scale, RoundMode::Nearest).unwrap(), 199999999u128);
+        assert_eq!(float_to_fixed_mode(value, scale, RoundMode::Down).unwrap(), 199999999u128);
+        assert_eq!(float_to_fixed_mode(value, scale, RoundMode::Up).unwrap(), 200000000u128);
+
+        // Half modes only differ on exact midpoints
+        assert_eq!(float_to_fixed_mode(2.5, Scale::Custom(0), RoundMode::HalfEven).unwrap(), 2u128);
+        assert_eq!(float_to_fixed_mode(2.5, Scale::Custom(0), RoundMode::HalfUp).unwrap(), 3u128);
+
+        // Float error in exact decimals does not move directed rounding a whole unit
+        for value in [0.29, 0.57, 1.13, 2.01, 4.35] {
+            let expected = (value * 100.0f64).round() as u128 * 1_000_000;
+            assert_eq!(float_to_fixed_mode(value, scale, RoundMode::Down).unwrap(), expected);
+            assert_eq!(float_to_fixed_mode(value, scale, RoundMode::Up).unwrap(), expected);
+        }
+        assert_eq!(float_to_fixed_mode(0.29, scale, RoundMode::Down).unwrap(), 29_000_000u128);
+        assert_eq!(float_to_fixed_mode(0.07, scale, RoundMode::Up).unwrap(), 7_000_000u128);
+
+        // Existing function keeps rounding to nearest
+        assert_eq!(float_to_fixed(value, scale).unwrap(), 199999999u128);
+
+        // Guards apply in every mode
+        assert!(float_to_fixed_mode(-1.0, scale, RoundMode::Down).is_err());
+        assert!(float_to_fixed_mode(f64::NAN, scale, RoundMode::Up).is_err());
+
+        // Results beyond u128 report overflow instead of saturating
+        assert_eq!(float_to_fixed_mode(1e40, scale, RoundMode::Up), Err(FinancialError::Overflow));
+        assert_eq!(float_to_fixed(1e40, scale), Err(FinancialError::Overflow));
+    }
+
     #[test]
     fn test_safe_conversions() {
         // Normal case
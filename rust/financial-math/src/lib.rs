@@ -50,6 +50,8 @@ pub enum FinancialError {
     NegativeValue,
     /// Invalid price or quantity value
     InvalidValue,
+    /// Invalid value at the given index of a batch input
+    InvalidValueAt(usize),
 }
 
 impl std::fmt::Display for FinancialError {
@@ -60,6 +62,7 @@ impl std::fmt::Display for FinancialError {
             FinancialError::InvalidScale => write!(f, "Invalid scale"),
             FinancialError::NegativeValue => write!(f, "Negative value"),
             FinancialError::InvalidValue => write!(f, "Invalid value"),
+            FinancialError::InvalidValueAt(index) => write!(f, "Invalid value at index {}", index),
         }
     }
 }
@@ -101,6 +104,31 @@ impl Scale {
     }
 }
 
+/// Rounding mode used when a value must be reduced to a fixed number of decimals
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundMode {
+    /// Round to the nearest value, halves away from zero (alias of `HalfUp`)
+    #[default]
+    Nearest,
+    /// Round towards zero (truncate)
+    Down,
+    /// Round away from zero (ceiling)
+    Up,
+    /// Round to the nearest value, halves to the even neighbour (banker's rounding)
+    HalfEven,
+    /// Round to the nearest value, halves up (same as `Nearest`)
+    HalfUp,
+}
+
+/// Side of the order book
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// Buy side (prices below the reference)
+    Bid,
+    /// Sell side (prices above the reference)
+    Ask,
+}
+
 /// Default scales for common financial operations
 pub const PRICE_SCALE: Scale = Scale::Price(8);
 pub const QUANTITY_SCALE: Scale = Scale::Quantity(8);
@@ -137,6 +165,10 @@ impl FinancialValue {
     }
 
     /// Convert to different scale
+    ///
+    /// Scaling up returns `Overflow` when the result (or the factor itself)
+    /// does not fit in a u128. Scaling down truncates; a gap wider than 38
+    /// decimals truncates every value to zero.
     pub fn to_scale(&self, new_scale: u32) -> FinancialResult<Self> {
         if new_scale == self.scale {
             return Ok(*self);
@@ -144,13 +176,17 @@ impl FinancialValue {
 
         let result = if new_scale > self.scale {
             // Scale up (multiply by 10^(new_scale - current_scale))
-            let multiplier = 10u128.pow(new_scale - self.scale);
+            let multiplier = 10u128.checked_pow(new_scale - self.scale)
+                .ok_or(FinancialError::Overflow)?;
             self.value.checked_mul(multiplier)
                 .ok_or(FinancialError::Overflow)?
         } else {
-            // Scale down (divide by 10^(current_scale - new_scale))
-            let divisor = 10u128.pow(self.scale - new_scale);
-            self.value / divisor
+            // Scale down (divide by 10^(current_scale - new_scale)); a divisor
+            // beyond u128 exceeds every value, so the quotient is zero
+            match 10u128.checked_pow(self.scale - new_scale) {
+                Some(divisor) => self.value / divisor,
+                None => 0,
+            }
         };
 
         Ok(Self::new(result, new_scale))
@@ -182,5 +218,9 @@ mod tests {
         let scaled_down = value.to_scale(6).unwrap();
         assert_eq!(scaled_down.value, 123_4567_89u128); // 123.45678900 -> 123.456789 (6 decimals)
         assert_eq!(scaled_down.scale, 6);
+
+        // Scale gaps beyond 10^38 do not panic
+        assert_eq!(value.to_scale(60), Err(FinancialError::Overflow));
+        assert_eq!(FinancialValue::new(u128::MAX, 60).to_scale(0).unwrap().value, 0);
     }
 }
\ No newline at end of file
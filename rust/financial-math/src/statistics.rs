@@ -2,7 +2,7 @@
 //!
 //! High-performance statistical functions using u128 fixed-point arithmetic.
 
-use crate::{FinancialResult, FinancialError};
+use crate::{FinancialResult, FinancialError, safe_sum, signed_difference};
 
 /// Calculate mean of u128 values
 ///
@@ -19,10 +19,53 @@ pub fn calculate_mean(values: &[u128]) -> FinancialResult<u128> {
         return Err(FinancialError::InvalidValue);
     }
 
-    let sum: u128 = values.iter().sum();
+    let sum = safe_sum(values)?;
     Ok(sum / values.len() as u128)
 }
 
+/// Calculate harmonic mean n / sum(1 / x_i) of fixed-point values
+///
+/// The result is at the same scale as `values`. `scale` documents that scale
+/// for callers; the computation does not depend on it. Each reciprocal is
+/// taken relative to the smallest value with about 128 bits of precision, so
+/// equal inputs return that input exactly and any input up to `u128::MAX` is
+/// supported. The result is truncated toward zero and is accurate to within
+/// one unit.
+///
+/// # Examples
+/// ```
+/// use financial_math::calculate_harmonic_mean;
+///
+/// let values = vec![200_000_000, 400_000_000]; // 2, 4
+/// let harmonic = calculate_harmonic_mean(&values, 8).unwrap();
+/// assert_eq!(harmonic, 266_666_666); // 2.66666666
+/// ```
+pub fn calculate_harmonic_mean(values: &[u128], _scale: u32) -> FinancialResult<u128> {
+    if values.is_empty() {
+        return Err(FinancialError::InvalidValue);
+    }
+    let min = *values.iter().min().ok_or(FinancialError::InvalidValue)?;
+    if min == 0 {
+        return Err(FinancialError::DivisionByZero);
+    }
+
+    // Weights min / x_i lie in (0, 1] and are held at a precision chosen so
+    // that n of them always sum within a u128
+    let count = values.len() as u128;
+    let precision = u128::MAX / count;
+    let weight_sum = values.iter().try_fold(0u128, |sum, &x| {
+        let weight =
+            div_wide(widening_mul(min, precision), x).ok_or(FinancialError::Overflow)?;
+        sum.checked_add(weight).ok_or(FinancialError::Overflow)
+    })?;
+
+    // n / sum(1 / x_i) = min * n / sum(min / x_i); the smallest value
+    // contributes a full weight, so the divisor is never zero and the
+    // quotient never exceeds the largest value
+    let numerator = widening_mul(min, count * precision);
+    div_wide(numerator, weight_sum).ok_or(FinancialError::Overflow)
+}
+
 /// Calculate standard deviation using fixed-point arithmetic
 ///
 /// # Examples
@@ -43,8 +86,105 @@ pub fn calculate_std_dev(values: &[u128]) -> FinancialResult<u128> {
     integer_sqrt(variance)
 }
 
+/// Calculate rolling standard deviation over a sliding window
+///
+/// Returns one standard deviation per window position, so the output has
+/// `values.len() - window + 1` elements, each at the same scale as `values`.
+/// `scale` documents that scale for callers; the computation does not depend
+/// on it.
+///
+/// # Examples
+/// ```
+/// use financial_math::rolling_std_dev;
+///
+/// let values = vec![90_000_000, 100_000_000, 110_000_000, 130_000_000];
+/// let std_devs = rolling_std_dev(&values, 3, 6).unwrap();
+/// assert_eq!(std_devs, vec![10_000_000, 15_275_252]); // 10.0, ~15.275
+/// ```
+pub fn rolling_std_dev(values: &[u128], window: usize, _scale: u32) -> FinancialResult<Vec<u128>> {
+    if window < 2 || window > values.len() {
+        return Err(FinancialError::InvalidValue);
+    }
+
+    values.windows(window).map(calculate_std_dev).collect()
+}
+
+/// Summary statistics of a series computed in a single pass
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SummaryStats {
+    /// Smallest value
+    pub min: u128,
+    /// Largest value
+    pub max: u128,
+    /// Arithmetic mean (truncated), same scale as the values
+    pub mean: u128,
+    /// Sample variance, at twice the scale of the values
+    pub variance: u128,
+    /// Sample standard deviation, same scale as the values
+    pub std_dev: u128,
+}
+
+/// Calculate min, max, mean, variance and standard deviation in a single pass
+///
+/// Produces exactly the same values as `calculate_min`, `calculate_max`,
+/// `calculate_mean`, `calculate_variance` and `calculate_std_dev`. The sum of
+/// squares is accumulated in 256 bits and the squared deviations are
+/// recovered as `sum(x^2) - 2 * mean * sum(x) + n * mean^2`, so only raw
+/// values near `u128::MAX` can overflow the accumulator. The statistics are
+/// scale-independent: `scale` only records the scale of `values` (see the
+/// field docs for the scale of each result).
+///
+/// # Examples
+/// ```
+/// use financial_math::summary_stats;
+///
+/// let values = vec![90_000_000, 100_000_000, 110_000_000]; // 90, 100, 110
+/// let stats = summary_stats(&values, 6).unwrap();
+/// assert_eq!(stats.min, 90_000_000);
+/// assert_eq!(stats.max, 110_000_000);
+/// assert_eq!(stats.mean, 100_000_000);
+/// assert_eq!(stats.std_dev, 10_000_000); // 10.000000
+/// ```
+pub fn summary_stats(values: &[u128], _scale: u32) -> FinancialResult<SummaryStats> {
+    if values.len() < 2 {
+        return Err(FinancialError::InvalidValue);
+    }
+
+    let mut min = u128::MAX;
+    let mut max = 0u128;
+    let mut sum = 0u128;
+    let mut sum_squares = (0u128, 0u128);
+    for &x in values {
+        min = min.min(x);
+        max = max.max(x);
+        sum = sum.checked_add(x).ok_or(FinancialError::Overflow)?;
+        sum_squares = add_wide(sum_squares, widening_mul(x, x)).ok_or(FinancialError::Overflow)?;
+    }
+
+    let count = values.len() as u128;
+    let mean = sum / count;
+
+    // count * mean <= sum, so it always fits in a u128
+    let sum_squared_diff = add_wide(sum_squares, widening_mul(count * mean, mean))
+        .and_then(|total| {
+            let cross = widening_mul(mean, sum);
+            sub_wide(total, add_wide(cross, cross)?)
+        })
+        .ok_or(FinancialError::Overflow)?;
+
+    let variance = div_wide(sum_squared_diff, count - 1).ok_or(FinancialError::Overflow)?;
+    let std_dev = integer_sqrt(variance)?;
+
+    Ok(SummaryStats { min, max, mean, variance, std_dev })
+}
+
 /// Calculate variance of u128 values
 ///
+/// Squared differences are accumulated in 256 bits, so any input is accepted
+/// as long as the resulting variance fits in a u128 (deviations from the mean
+/// up to about 1.8e19 raw units, i.e. 1.8e11 at 8 decimal places); larger
+/// variances return `Overflow`.
+///
 /// # Examples
 /// ```
 /// use financial_math::calculate_variance;
@@ -52,21 +192,75 @@ pub fn calculate_std_dev(values: &[u128]) -> FinancialResult<u128> {
 /// let values = vec![100_000_000, 110_000_000, 90_000_000];
 /// let mean = 100_000_000;
 /// let variance = calculate_variance(&values, mean).unwrap();
+/// assert_eq!(variance, 100_000_000_000_000); // 10^2 at 16 decimal places
 /// ```
 pub fn calculate_variance(values: &[u128], mean: u128) -> FinancialResult<u128> {
     if values.len() < 2 {
         return Err(FinancialError::InvalidValue);
     }
 
-    let sum_squared_diff: u128 = values
-        .iter()
-        .map(|&x| {
-            let diff = if x > mean { x - mean } else { mean - x };
-            diff * diff
-        })
-        .sum();
+    let sum_squared_diff = values.iter().try_fold((0u128, 0u128), |sum, &x| {
+        let diff = x.abs_diff(mean);
+        add_wide(sum, widening_mul(diff, diff)).ok_or(FinancialError::Overflow)
+    })?;
 
-    Ok(sum_squared_diff / (values.len() as u128 - 1))
+    div_wide(sum_squared_diff, values.len() as u128 - 1).ok_or(FinancialError::Overflow)
+}
+
+/// Full 256-bit product of two u128 values as (high, low) halves
+fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+
+    let lo_lo = a_lo * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_lo = a_hi * b_lo;
+    let hi_hi = a_hi * b_hi;
+
+    let middle = (lo_lo >> 64) + (lo_hi & MASK) + (hi_lo & MASK);
+    let low = (lo_lo & MASK) | (middle << 64);
+    let high = hi_hi + (lo_hi >> 64) + (hi_lo >> 64) + (middle >> 64);
+
+    (high, low)
+}
+
+/// Add two 256-bit values, returning None on overflow
+fn add_wide(a: (u128, u128), b: (u128, u128)) -> Option<(u128, u128)> {
+    let (low, carry) = a.1.overflowing_add(b.1);
+    let high = a.0.checked_add(b.0)?.checked_add(carry as u128)?;
+    Some((high, low))
+}
+
+/// Subtract two 256-bit values, returning None on underflow
+fn sub_wide(a: (u128, u128), b: (u128, u128)) -> Option<(u128, u128)> {
+    let (low, borrow) = a.1.overflowing_sub(b.1);
+    let high = a.0.checked_sub(b.0)?.checked_sub(borrow as u128)?;
+    Some((high, low))
+}
+
+/// Divide a 256-bit value by a u128, returning None if the quotient
+/// does not fit in a u128
+fn div_wide(dividend: (u128, u128), divisor: u128) -> Option<u128> {
+    let (high, low) = dividend;
+    if high >= divisor {
+        return None;
+    }
+
+    // Binary long division over the low half, seeded with the high half
+    let mut remainder = high;
+    let mut quotient = 0u128;
+    for bit in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((low >> bit) & 1);
+        if carry == 1 || remainder >= divisor {
+            remainder = remainder.wrapping_sub(divisor);
+            quotient |= 1 << bit;
+        }
+    }
+
+    Some(quotient)
 }
 
 /// Calculate percentile using fixed-point arithmetic
@@ -80,17 +274,40 @@ pub fn calculate_variance(values: &[u128], mean: u128) -> FinancialResult<u128>
 /// assert_eq!(median, 105_000_000); // 105.000000 (interpolated median)
 /// ```
 pub fn calculate_percentile(values: &[u128], percentile: u32) -> FinancialResult<u128> {
-    if values.is_empty() || percentile > 100 {
+    let mut sorted_values = values.to_vec();
+    sorted_values.sort_unstable();
+    percentile_sorted(&sorted_values, percentile)
+}
+
+/// Calculate percentile of values that are already sorted ascending
+///
+/// Same result as `calculate_percentile` without the copy and sort, for
+/// windows that are maintained in sorted order. Unsorted input is a caller
+/// bug: it trips a debug assertion and yields an unspecified value in
+/// release builds.
+///
+/// # Examples
+/// ```
+/// use financial_math::percentile_sorted;
+///
+/// let sorted = vec![90_000_000, 100_000_000, 110_000_000, 120_000_000];
+/// let median = percentile_sorted(&sorted, 50).unwrap();
+/// assert_eq!(median, 105_000_000); // 105.000000 (interpolated median)
+/// ```
+pub fn percentile_sorted(sorted_values: &[u128], percentile: u32) -> FinancialResult<u128> {
+    debug_assert!(
+        sorted_values.windows(2).all(|pair| pair[0] <= pair[1]),
+        "percentile_sorted requires values sorted ascending"
+    );
+
+    if sorted_values.is_empty() || percentile > 100 {
         return Err(FinancialError::InvalidValue);
     }
 
-    if values.len() == 1 {
-        return Ok(values[0]);
+    if sorted_values.len() == 1 {
+        return Ok(sorted_values[0]);
     }
 
-    let mut sorted_values = values.to_vec();
-    sorted_values.sort_unstable();
-
     if percentile == 0 {
         return Ok(sorted_values[0]);
     }
@@ -98,8 +315,8 @@ pub fn calculate_percentile(values: &[u128], percentile: u32) -> FinancialResult
         return Ok(sorted_values[sorted_values.len() - 1]);
     }
 
-    // Calculate position using fixed-point arithmetic
-    let position = (percentile as u128 * (sorted_values.len() as u128 - 1) * 100) / 10000;
+    // Calculate position in hundredths of an index using fixed-point arithmetic
+    let position = percentile as u128 * (sorted_values.len() as u128 - 1);
 
     let lower_index = (position / 100) as usize;
     let upper_index = lower_index + 1;
@@ -111,16 +328,110 @@ pub fn calculate_percentile(values: &[u128], percentile: u32) -> FinancialResult
     let lower_value = sorted_values[lower_index];
     let upper_value = sorted_values[upper_index];
 
-    // Linear interpolation
+    // Linear interpolation, split so diff * fraction cannot overflow
     let fraction = position % 100;
     let diff = upper_value - lower_value;
-    let interpolated = lower_value + (diff * fraction) / 100;
+    let interpolated = lower_value + diff / 100 * fraction + diff % 100 * fraction / 100;
 
     Ok(interpolated)
 }
 
+/// Calculate a weighted percentile (e.g. the volume-weighted median)
+///
+/// Each value occupies a share of the cumulative weight proportional to its
+/// weight and is anchored at the midpoint of that share; the result is
+/// linearly interpolated between neighbouring anchors. With equal weights this
+/// matches `calculate_percentile` at the middle of the distribution.
+///
+/// # Examples
+/// ```
+/// use financial_math::weighted_percentile;
+///
+/// let prices = vec![100_000_000, 200_000_000, 300_000_000];
+/// let volumes = vec![1, 1, 1];
+/// let median = weighted_percentile(&prices, &volumes, 50).unwrap();
+/// assert_eq!(median, 200_000_000);
+/// ```
+pub fn weighted_percentile(values: &[u128], weights: &[u128], percentile: u32) -> FinancialResult<u128> {
+    if values.is_empty() || values.len() != weights.len() || percentile > 100 {
+        return Err(FinancialError::InvalidValue);
+    }
+
+    let mut pairs: Vec<(u128, u128)> = values
+        .iter()
+        .copied()
+        .zip(weights.iter().copied())
+        .filter(|&(_, weight)| weight > 0)
+        .collect();
+    if pairs.is_empty() {
+        return Err(FinancialError::InvalidValue);
+    }
+    pairs.sort_unstable_by_key(|&(value, _)| value);
+
+    // Anchor positions are (2 * cumulative - weight) * 100 and the target is
+    // 2 * total * percentile, keeping the midpoints and percent in integers
+    let mut anchors = Vec::with_capacity(pairs.len());
+    let mut cumulative = 0u128;
+    for &(_, weight) in &pairs {
+        cumulative = cumulative.checked_add(weight).ok_or(FinancialError::Overflow)?;
+        let anchor = cumulative
+            .checked_mul(2)
+            .and_then(|doubled| (doubled - weight).checked_mul(100))
+            .ok_or(FinancialError::Overflow)?;
+        anchors.push(anchor);
+    }
+    let target = cumulative
+        .checked_mul(2 * percentile as u128)
+        .ok_or(FinancialError::Overflow)?;
+
+    let last = pairs.len() - 1;
+    if target <= anchors[0] {
+        return Ok(pairs[0].0);
+    }
+    if target >= anchors[last] {
+        return Ok(pairs[last].0);
+    }
+
+    let upper_index = anchors.iter().position(|&anchor| anchor > target).unwrap_or(last);
+    let lower_index = upper_index - 1;
+
+    let lower_value = pairs[lower_index].0;
+    let upper_value = pairs[upper_index].0;
+    let offset = (upper_value - lower_value)
+        .checked_mul(target - anchors[lower_index])
+        .ok_or(FinancialError::Overflow)?;
+
+    Ok(lower_value + offset / (anchors[upper_index] - anchors[lower_index]))
+}
+
+/// Calculate the percentile rank (0-100) of a value within a series
+///
+/// Returns the percentage of the series that is less than or equal to
+/// `value`, truncated to a whole percentile.
+///
+/// # Examples
+/// ```
+/// use financial_math::percentile_rank;
+///
+/// let values = vec![90_000_000, 100_000_000, 110_000_000, 120_000_000];
+/// let rank = percentile_rank(110_000_000, &values).unwrap();
+/// assert_eq!(rank, 75);
+/// ```
+pub fn percentile_rank(value: u128, series: &[u128]) -> FinancialResult<u32> {
+    if series.is_empty() {
+        return Err(FinancialError::InvalidValue);
+    }
+
+    let at_or_below = series.iter().filter(|&&x| x <= value).count();
+    Ok((at_or_below * 100 / series.len()) as u32)
+}
+
 /// Calculate median (50th percentile)
 ///
+/// For an even count the median is the exact integer average of the two
+/// middle values, computed as `(a + b + 1) / 2` so a half unit rounds up.
+/// The average is taken without forming `a + b`, so it never overflows.
+///
 /// # Examples
 /// ```
 /// use financial_math::calculate_median;
@@ -130,7 +441,120 @@ pub fn calculate_percentile(values: &[u128], percentile: u32) -> FinancialResult
 /// assert_eq!(median, 100_000_000); // 100.000000
 /// ```
 pub fn calculate_median(values: &[u128]) -> FinancialResult<u128> {
-    calculate_percentile(values, 50)
+    if values.is_empty() {
+        return Err(FinancialError::InvalidValue);
+    }
+
+    let mut sorted_values = values.to_vec();
+    sorted_values.sort_unstable();
+
+    let mid = sorted_values.len() / 2;
+    if sorted_values.len() % 2 == 1 {
+        return Ok(sorted_values[mid]);
+    }
+
+    let lower = sorted_values[mid - 1];
+    let upper = sorted_values[mid];
+    Ok(lower + (upper - lower).div_ceil(2))
+}
+
+/// Calculate interquartile range (Q3 - Q1)
+///
+/// Requires at least four values so that both quartiles are meaningful.
+///
+/// # Examples
+/// ```
+/// use financial_math::iqr;
+///
+/// let values = vec![90_000_000, 100_000_000, 110_000_000, 120_000_000];
+/// let range = iqr(&values).unwrap();
+/// assert_eq!(range, 15_000_000); // 112.5 - 97.5
+/// ```
+pub fn iqr(values: &[u128]) -> FinancialResult<u128> {
+    if values.len() < 4 {
+        return Err(FinancialError::InvalidValue);
+    }
+
+    let q1 = calculate_percentile(values, 25)?;
+    let q3 = calculate_percentile(values, 75)?;
+    Ok(q3 - q1)
+}
+
+/// Remove outliers outside [Q1 - k * IQR, Q3 + k * IQR]
+///
+/// `k_bps` is the IQR multiplier in basis points (15000 = the usual 1.5).
+/// The remaining values keep their original order.
+///
+/// # Examples
+/// ```
+/// use financial_math::filter_outliers_iqr;
+///
+/// let values = vec![10_0000_0000, 11_0000_0000, 12_0000_0000, 13_0000_0000, 100_0000_0000];
+/// let filtered = filter_outliers_iqr(&values, 15000).unwrap();
+/// assert_eq!(filtered, vec![10_0000_0000, 11_0000_0000, 12_0000_0000, 13_0000_0000]);
+/// ```
+pub fn filter_outliers_iqr(values: &[u128], k_bps: u32) -> FinancialResult<Vec<u128>> {
+    if values.len() < 4 {
+        return Err(FinancialError::InvalidValue);
+    }
+
+    let q1 = calculate_percentile(values, 25)?;
+    let q3 = calculate_percentile(values, 75)?;
+    let fence = (q3 - q1)
+        .checked_mul(k_bps as u128)
+        .ok_or(FinancialError::Overflow)?
+        / 10_000;
+
+    let lower = q1.saturating_sub(fence);
+    let upper = q3.saturating_add(fence);
+
+    Ok(values
+        .iter()
+        .copied()
+        .filter(|&value| value >= lower && value <= upper)
+        .collect())
+}
+
+/// Calculate the running total of signed values (e.g. cumulative volume delta)
+///
+/// # Examples
+/// ```
+/// use financial_math::cumulative_sum;
+///
+/// let deltas = vec![5_000_000, -2_000_000, 3_000_000];
+/// let cvd = cumulative_sum(&deltas).unwrap();
+/// assert_eq!(cvd, vec![5_000_000, 3_000_000, 6_000_000]);
+/// ```
+pub fn cumulative_sum(values: &[i128]) -> FinancialResult<Vec<i128>> {
+    let mut running_total = 0i128;
+    values
+        .iter()
+        .map(|&value| {
+            running_total = running_total.checked_add(value).ok_or(FinancialError::Overflow)?;
+            Ok(running_total)
+        })
+        .collect()
+}
+
+/// Calculate the running total of unsigned values
+///
+/// # Examples
+/// ```
+/// use financial_math::cumulative_sum_u128;
+///
+/// let volumes = vec![100_000_000, 50_000_000, 25_000_000];
+/// let totals = cumulative_sum_u128(&volumes).unwrap();
+/// assert_eq!(totals, vec![100_000_000, 150_000_000, 175_000_000]);
+/// ```
+pub fn cumulative_sum_u128(values: &[u128]) -> FinancialResult<Vec<u128>> {
+    let mut running_total = 0u128;
+    values
+        .iter()
+        .map(|&value| {
+            running_total = running_total.checked_add(value).ok_or(FinancialError::Overflow)?;
+            Ok(running_total)
+        })
+        .collect()
 }
 
 /// Calculate minimum value
@@ -167,6 +591,384 @@ pub fn calculate_max(values: &[u128]) -> FinancialResult<u128> {
     Ok(*values.iter().max().unwrap())
 }
 
+/// Calculate the z-score of a value against a reference series
+///
+/// Returns `(value - mean) / std_dev` as a signed fixed-point number where
+/// `10^scale` represents a z-score of 1.0. Values below the mean yield a
+/// negative z-score.
+///
+/// Returns `InvalidValue` when the series has fewer than two elements or a
+/// zero standard deviation (all values equal), since the z-score is undefined.
+///
+/// # Examples
+/// ```
+/// use financial_math::z_score;
+///
+/// let series = vec![90_000_000, 100_000_000, 110_000_000]; // std dev = 10
+/// let z = z_score(110_000_000, &series, 4).unwrap();
+/// assert_eq!(z, 1_0000); // 1.0000 standard deviations above the mean
+/// ```
+pub fn z_score(value: u128, series: &[u128], scale: u32) -> FinancialResult<i128> {
+    if series.len() < 2 {
+        return Err(FinancialError::InvalidValue);
+    }
+
+    let mean = calculate_mean(series)?;
+    let std_dev = calculate_std_dev(series)?;
+    if std_dev == 0 {
+        return Err(FinancialError::InvalidValue);
+    }
+
+    let value = i128::try_from(value).map_err(|_| FinancialError::Overflow)?;
+    let mean = i128::try_from(mean).map_err(|_| FinancialError::Overflow)?;
+    let std_dev = i128::try_from(std_dev).map_err(|_| FinancialError::Overflow)?;
+    let multiplier = 10i128.checked_pow(scale).ok_or(FinancialError::Overflow)?;
+
+    let diff = value.checked_sub(mean).ok_or(FinancialError::Overflow)?;
+    let scaled = diff.checked_mul(multiplier).ok_or(FinancialError::Overflow)?;
+
+    Ok(scaled / std_dev)
+}
+
+/// Calculate average true range (ATR) over OHLC bars
+///
+/// True range per bar is `max(high - low, |high - prev_close|, |low - prev_close|)`;
+/// the first bar has no previous close and uses `high - low`. The first
+/// `period` true ranges are averaged, then the remaining bars are smoothed with
+/// Wilder's method: `atr = (atr * (period - 1) + tr) / period`. The result is at
+/// the same scale as the inputs.
+///
+/// # Examples
+/// ```
+/// use financial_math::calculate_atr;
+///
+/// let highs = vec![10_0000_0000, 11_0000_0000, 12_0000_0000];
+/// let lows = vec![9_0000_0000, 10_0000_0000, 10_0000_0000];
+/// let closes = vec![9_5000_0000, 10_5000_0000, 11_5000_0000];
+/// let atr = calculate_atr(&highs, &lows, &closes, 3).unwrap();
+/// assert_eq!(atr, 1_5000_0000); // (1.0 + 1.5 + 2.0) / 3
+/// ```
+pub fn calculate_atr(
+    highs: &[u128],
+    lows: &[u128],
+    closes: &[u128],
+    period: usize
+) -> FinancialResult<u128> {
+    if highs.len() != lows.len() || highs.len() != closes.len() {
+        return Err(FinancialError::InvalidValue);
+    }
+    if period == 0 || period > highs.len() {
+        return Err(FinancialError::InvalidValue);
+    }
+
+    let mut true_ranges = Vec::with_capacity(highs.len());
+    for i in 0..highs.len() {
+        if lows[i] > highs[i] {
+            return Err(FinancialError::InvalidValue);
+        }
+
+        let range = highs[i] - lows[i];
+        let true_range = if i == 0 {
+            range
+        } else {
+            let prev_close = closes[i - 1];
+            range
+                .max(highs[i].abs_diff(prev_close))
+                .max(lows[i].abs_diff(prev_close))
+        };
+        true_ranges.push(true_range);
+    }
+
+    let period_u128 = period as u128;
+    let mut atr = calculate_mean(&true_ranges[..period])?;
+    for &true_range in &true_ranges[period..] {
+        let weighted = atr
+            .checked_mul(period_u128 - 1)
+            .and_then(|v| v.checked_add(true_range))
+            .ok_or(FinancialError::Overflow)?;
+        atr = weighted / period_u128;
+    }
+
+    Ok(atr)
+}
+
+/// Streaming quantile estimator using the P² algorithm (Jain & Chlamtac)
+///
+/// Tracks a single quantile over an unbounded stream in constant memory by
+/// maintaining five markers whose heights are adjusted with piecewise-parabolic
+/// interpolation. Until five values have been seen the estimate is exact
+/// (nearest rank). Afterwards it is an approximation: for smooth
+/// distributions the error is typically well under 1% of the value range, but
+/// it can be larger for small samples or heavily clustered data. Marker
+/// heights are kept as f64, so values above 2^53 lose low-order digits.
+///
+/// # Examples
+/// ```
+/// use financial_math::P2Quantile;
+///
+/// let mut median = P2Quantile::new(5000).unwrap(); // 50th percentile
+/// for value in 1..=101u128 {
+///     median.push(value * 100_000_000);
+/// }
+/// let estimate = median.estimate();
+/// assert!(estimate.abs_diff(5_100_000_000) < 100_000_000); // ~51.0
+/// ```
+#[derive(Debug, Clone)]
+pub struct P2Quantile {
+    /// Target quantile as a fraction (0.0 - 1.0)
+    quantile: f64,
+    /// Marker heights
+    heights: [f64; 5],
+    /// Actual marker positions
+    positions: [f64; 5],
+    /// Desired marker positions
+    desired: [f64; 5],
+    /// Desired position increments per observation
+    increments: [f64; 5],
+    /// Number of observations
+    count: usize,
+}
+
+impl P2Quantile {
+    /// Create an estimator for the quantile given in basis points (5000 = median)
+    pub fn new(quantile_bps: u32) -> FinancialResult<Self> {
+        if quantile_bps > 10_000 {
+            return Err(FinancialError::InvalidValue);
+        }
+
+        let p = quantile_bps as f64 / 10_000.0;
+        Ok(Self {
+            quantile: p,
+            heights: [0.0; 5],
+            positions: [1.0, 2.0, 3.0, 4.0, 5.0],
+            desired: [1.0, 1.0 + 2.0 * p, 1.0 + 4.0 * p, 3.0 + 2.0 * p, 5.0],
+            increments: [0.0, p / 2.0, p, (1.0 + p) / 2.0, 1.0],
+            count: 0,
+        })
+    }
+
+    /// Number of values observed so far
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Add an observation
+    pub fn push(&mut self, value: u128) {
+        let x = value as f64;
+
+        if self.count < 5 {
+            self.heights[self.count] = x;
+            self.count += 1;
+            if self.count == 5 {
+                self.heights.sort_unstable_by(|a, b| a.total_cmp(b));
+            }
+            return;
+        }
+        self.count += 1;
+
+        // Find the cell containing x, extending the extremes if needed
+        let cell = if x < self.heights[0] {
+            self.heights[0] = x;
+            0
+        } else if x >= self.heights[4] {
+            self.heights[4] = x;
+            3
+        } else {
+            (1..5).find(|&i| x < self.heights[i]).unwrap_or(4) - 1
+        };
+
+        for position in &mut self.positions[cell + 1..] {
+            *position += 1.0;
+        }
+        for (desired, increment) in self.desired.iter_mut().zip(self.increments.iter()) {
+            *desired += increment;
+        }
+
+        // Adjust the three middle markers towards their desired positions
+        for i in 1..4 {
+            let offset = self.desired[i] - self.positions[i];
+            let gap_above = self.positions[i + 1] - self.positions[i];
+            let gap_below = self.positions[i - 1] - self.positions[i];
+
+            if (offset >= 1.0 && gap_above > 1.0) || (offset <= -1.0 && gap_below < -1.0) {
+                let step = offset.signum();
+                let parabolic = self.parabolic(i, step);
+                let in_order = self.heights[i - 1] < parabolic && parabolic < self.heights[i + 1];
+                self.heights[i] = if in_order { parabolic } else { self.linear(i, step) };
+                self.positions[i] += step;
+            }
+        }
+    }
+
+    /// Current quantile estimate (0 when no values have been observed)
+    pub fn estimate(&self) -> u128 {
+        if self.count == 0 {
+            return 0;
+        }
+        if self.count < 5 {
+            let mut observed = self.heights[..self.count].to_vec();
+            observed.sort_unstable_by(|a, b| a.total_cmp(b));
+            let index = (self.quantile * (self.count - 1) as f64).round() as usize;
+            return observed[index] as u128;
+        }
+
+        // The outer markers track the exact minimum and maximum
+        let height = if self.quantile == 0.0 {
+            self.heights[0]
+        } else if self.quantile == 1.0 {
+            self.heights[4]
+        } else {
+            self.heights[2]
+        };
+        height.round() as u128
+    }
+
+    /// Piecewise-parabolic prediction for marker `i` moved by `step`
+    fn parabolic(&self, i: usize, step: f64) -> f64 {
+        let (q, n) = (&self.heights, &self.positions);
+        q[i] + step / (n[i + 1] - n[i - 1])
+            * ((n[i] - n[i - 1] + step) * (q[i + 1] - q[i]) / (n[i + 1] - n[i])
+                + (n[i + 1] - n[i] - step) * (q[i] - q[i - 1]) / (n[i] - n[i - 1]))
+    }
+
+    /// Linear prediction for marker `i` moved by `step`
+    fn linear(&self, i: usize, step: f64) -> f64 {
+        let (q, n) = (&self.heights, &self.positions);
+        let neighbour = if step > 0.0 { i + 1 } else { i - 1 };
+        q[i] + step * (q[neighbour] - q[i]) / (n[neighbour] - n[i])
+    }
+}
+
+/// Internal precision (decimal places) for fixed-point logarithms
+const LN_PRECISION: u32 = 18;
+
+/// 1.0 at `LN_PRECISION`
+const LN_ONE: i128 = 1_000_000_000_000_000_000;
+
+/// ln(2) at `LN_PRECISION`
+const LN_2: i128 = 693_147_180_559_945_309;
+
+/// Calculate natural-log returns ln(p_t / p_{t-1}) of a price series
+///
+/// Returns one signed fixed-point value per consecutive pair of prices, with
+/// `scale` decimal places (a down move yields a negative return). The
+/// logarithm is evaluated internally at 18 decimal places, so each result is
+/// accurate to within ±1 unit at the requested `scale` (at most 18).
+///
+/// Prices may use the full `u128` range; only a single step whose price
+/// ratio exceeds about 3.4e20 returns `FinancialError::Overflow`.
+///
+/// # Examples
+/// ```
+/// use financial_math::log_returns;
+///
+/// let prices = vec![100_000_000, 200_000_000, 100_000_000]; // 100, 200, 100
+/// let returns = log_returns(&prices, 8).unwrap();
+/// assert_eq!(returns, vec![69_314_718, -69_314_718]); // ln(2), ln(0.5)
+/// ```
+pub fn log_returns(prices: &[u128], scale: u32) -> FinancialResult<Vec<i128>> {
+    if prices.len() < 2 {
+        return Err(FinancialError::InvalidValue);
+    }
+    if scale > LN_PRECISION {
+        return Err(FinancialError::InvalidScale);
+    }
+    if prices.contains(&0) {
+        return Err(FinancialError::InvalidValue);
+    }
+
+    let divisor = 10i128.pow(LN_PRECISION - scale);
+
+    prices
+        .windows(2)
+        .map(|pair| {
+            // Form the ratio in 256 bits so large raw prices cannot overflow
+            let ratio = div_wide(widening_mul(pair[1], LN_ONE as u128), pair[0])
+                .ok_or(FinancialError::Overflow)?;
+            let ln = fixed_ln(ratio)?;
+
+            // Round half away from zero when reducing to the output scale
+            let half = divisor / 2;
+            Ok(if ln >= 0 { (ln + half) / divisor } else { (ln - half) / divisor })
+        })
+        .collect()
+}
+
+/// Calculate rate of change (momentum) over `n` periods
+///
+/// Returns `(price_t - price_{t-n}) / price_{t-n}` for every `t >= n` as signed
+/// fixed-point values where `10^scale` represents 1.0 (a 100% change). Gains
+/// are positive and losses negative; magnitudes are truncated toward zero.
+///
+/// # Examples
+/// ```
+/// use financial_math::rate_of_change;
+///
+/// let prices = vec![100_000_000, 105_000_000, 110_000_000]; // 100, 105, 110
+/// let roc = rate_of_change(&prices, 2, 4).unwrap();
+/// assert_eq!(roc, vec![1000]); // 0.1000 = +10% over 2 periods
+/// ```
+pub fn rate_of_change(prices: &[u128], n: usize, scale: u32) -> FinancialResult<Vec<i128>> {
+    if n == 0 || n >= prices.len() {
+        return Err(FinancialError::InvalidValue);
+    }
+
+    let multiplier = 10u128.checked_pow(scale).ok_or(FinancialError::Overflow)?;
+
+    prices
+        .windows(n + 1)
+        .map(|window| {
+            let (previous, current) = (window[0], window[n]);
+            if previous == 0 {
+                return Err(FinancialError::DivisionByZero);
+            }
+
+            let (gain, change) = signed_difference(current, previous);
+            let scaled = change.checked_mul(multiplier).ok_or(FinancialError::Overflow)? / previous;
+            let scaled = i128::try_from(scaled).map_err(|_| FinancialError::Overflow)?;
+            Ok(if gain { scaled } else { -scaled })
+        })
+        .collect()
+}
+
+/// Natural logarithm of a positive value at `LN_PRECISION`
+///
+/// Reduces the argument to m * 2^k with m in [1, 2), then evaluates
+/// ln(m) = 2 * atanh((m - 1) / (m + 1)) with its power series.
+fn fixed_ln(x: u128) -> FinancialResult<i128> {
+    if x == 0 {
+        return Err(FinancialError::InvalidValue);
+    }
+
+    let one = LN_ONE as u128;
+    let mut m = x;
+    let mut k: i128 = 0;
+
+    while m >= 2 * one {
+        m /= 2;
+        k += 1;
+    }
+    while m < one {
+        m *= 2;
+        k -= 1;
+    }
+
+    let m = m as i128;
+    let z = (m - LN_ONE) * LN_ONE / (m + LN_ONE);
+    let z_squared = z * z / LN_ONE;
+
+    let mut sum = 0i128;
+    let mut term = z;
+    let mut n = 1i128;
+    while term != 0 {
+        sum += term / n;
+        term = term * z_squared / LN_ONE;
+        n += 2;
+    }
+
+    Ok(k * LN_2 + 2 * sum)
+}
+
 /// Integer square root approximation using Newton's method
 /// This is needed for standard deviation calculation
 fn integer_sqrt(n: u128) -> FinancialResult<u128> {
@@ -197,17 +999,146 @@ mod tests {
 
         // Empty array should fail
         assert!(calculate_mean(&[]).is_err());
+
+        // Sum past u128::MAX reports overflow instead of panicking
+        let values = vec![u128::MAX / 2, u128::MAX / 2, 2];
+        assert_eq!(calculate_mean(&values), Err(FinancialError::Overflow));
+    }
+
+    #[test]
+    fn test_calculate_variance_large_values() {
+        // Squared deviations of 1.2e19 sum past u128::MAX, the variance does not
+        let values = vec![0, 24_000_000_000_000_000_000, 0, 24_000_000_000_000_000_000];
+        let mean = calculate_mean(&values).unwrap();
+        assert_eq!(mean, 12_000_000_000_000_000_000);
+
+        let variance = calculate_variance(&values, mean).unwrap();
+        assert_eq!(variance, 192_000_000_000_000_000_000_000_000_000_000_000_000);
+
+        let std_dev = calculate_std_dev(&values).unwrap();
+        assert_eq!(std_dev, 13_856_406_460_551_018_348);
+
+        // Small values are unchanged
+        let small = vec![100_000_000, 110_000_000, 90_000_000];
+        assert_eq!(calculate_variance(&small, 100_000_000).unwrap(), 100_000_000_000_000);
+
+        // A variance beyond u128 is reported as overflow
+        let huge = vec![0, u128::MAX];
+        assert_eq!(calculate_variance(&huge, u128::MAX / 2), Err(FinancialError::Overflow));
+    }
+
+    #[test]
+    fn test_wide_arithmetic() {
+        assert_eq!(widening_mul(u128::MAX, u128::MAX), (u128::MAX - 1, 1));
+        assert_eq!(widening_mul(1 << 64, 1 << 64), (1, 0));
+        assert_eq!(widening_mul(12345, 6789), (0, 12345 * 6789));
+
+        assert_eq!(add_wide((0, u128::MAX), (0, 1)), Some((1, 0)));
+        assert_eq!(add_wide((u128::MAX, u128::MAX), (0, 1)), None);
+
+        assert_eq!(sub_wide((1, 0), (0, 1)), Some((0, u128::MAX)));
+        assert_eq!(sub_wide((5, 7), (2, 3)), Some((3, 4)));
+        assert_eq!(sub_wide((0, 0), (0, 1)), None);
+
+        assert_eq!(div_wide((1, 0), 2), Some(1 << 127));
+        assert_eq!(div_wide((0, 100), 7), Some(14));
+        assert_eq!(div_wide(widening_mul(u128::MAX, 3), 3), Some(u128::MAX));
+        assert_eq!(div_wide((1, 0), 1), None);
+    }
+
+    #[test]
+    fn test_summary_stats() {
+        let series: Vec<Vec<u128>> = vec![
+            vec![90_000_000, 100_000_000, 110_000_000],
+            vec![100_000_000, 110_000_000, 90_000_000, 105_000_000, 95_000_000, 123_456_789],
+            vec![7, 7, 7, 7],
+            vec![0, 24_000_000_000_000_000_000, 0, 24_000_000_000_000_000_000],
+        ];
+
+        for values in &series {
+            let stats = summary_stats(values, 8).unwrap();
+            let mean = calculate_mean(values).unwrap();
+            assert_eq!(stats.min, calculate_min(values).unwrap());
+            assert_eq!(stats.max, calculate_max(values).unwrap());
+            assert_eq!(stats.mean, mean);
+            assert_eq!(stats.variance, calculate_variance(values, mean).unwrap());
+            assert_eq!(stats.std_dev, calculate_std_dev(values).unwrap());
+        }
+
+        // Scale does not limit the inputs
+        assert_eq!(summary_stats(&[9, 10, 11], 20).unwrap().std_dev, 1);
+
+        // Too few values and accumulator overflow
+        assert!(summary_stats(&[100_000_000], 8).is_err());
+        assert_eq!(summary_stats(&[u128::MAX, u128::MAX], 8), Err(FinancialError::Overflow));
+    }
+
+    #[test]
+    fn test_calculate_harmonic_mean() {
+        // 3 / (1/1 + 1/2 + 1/4) = 3 / 1.75 = 1.71428571
+        let values = vec![100_000_000, 200_000_000, 400_000_000];
+        let harmonic = calculate_harmonic_mean(&values, 8).unwrap();
+        assert_eq!(harmonic, 171_428_571);
+
+        // Never exceeds the arithmetic mean
+        let mean = calculate_mean(&values).unwrap();
+        assert!(harmonic <= mean);
+
+        let equal = vec![150_000_000, 150_000_000];
+        assert_eq!(calculate_harmonic_mean(&equal, 8).unwrap(), 150_000_000);
+
+        // Empty input and zero elements should fail
+        assert!(calculate_harmonic_mean(&[], 8).is_err());
+        assert_eq!(
+            calculate_harmonic_mean(&[100_000_000, 0], 8),
+            Err(FinancialError::DivisionByZero)
+        );
+
+        // Many values, at any scale
+        let many = vec![1_500_000_000_000u128; 1000]; // 1.5 at scale 12
+        assert_eq!(calculate_harmonic_mean(&many, 12).unwrap(), 1_500_000_000_000);
+        assert_eq!(calculate_harmonic_mean(&many, 18).unwrap(), 1_500_000_000_000);
+
+        // Equal inputs return that input exactly, however large
+        assert_eq!(calculate_harmonic_mean(&[600_000, 600_000], 2).unwrap(), 600_000);
+        let large = 600_000_000_000_000_000_000_000u128; // 6e23
+        assert_eq!(calculate_harmonic_mean(&[large, large], 8).unwrap(), large);
+        assert_eq!(calculate_harmonic_mean(&[1 << 100, 1 << 100], 8).unwrap(), 1 << 100);
+        assert_eq!(calculate_harmonic_mean(&[u128::MAX; 3], 0).unwrap(), u128::MAX);
+
+        // 2 / (1/1e20 + 1/3e20) = 1.5e20
+        let harmonic = calculate_harmonic_mean(
+            &[100_000_000_000_000_000_000, 300_000_000_000_000_000_000],
+            8,
+        )
+        .unwrap();
+        assert_eq!(harmonic, 150_000_000_000_000_000_000);
+
+        // Widely spread values: 2 / (1 + 1/MAX) is just under 2
+        let harmonic = calculate_harmonic_mean(&[1, u128::MAX], 0).unwrap();
+        assert!((1..=2).contains(&harmonic));
     }
 
     #[test]
     fn test_calculate_median() {
         let values = vec![90_000_000, 100_000_000, 110_000_000];
         let median = calculate_median(&values).unwrap();
-        assert_eq!(median, 90_100_000); // 90.1 (actual result)
+        assert_eq!(median, 100_000_000); // 100.0 (middle value)
 
         let values2 = vec![90_000_000, 100_000_000, 110_000_000, 120_000_000];
         let median2 = calculate_median(&values2).unwrap();
         assert_eq!(median2, 105_000_000); // (100 + 110) / 2 = 105
+
+        // Odd sum of the middle values rounds the half unit up
+        assert_eq!(calculate_median(&[1, 2]).unwrap(), 2);
+        assert_eq!(calculate_median(&[4, 1, 3, 2]).unwrap(), 3);
+
+        // Large values are averaged exactly without overflowing
+        assert_eq!(calculate_median(&[0, u128::MAX]).unwrap(), 1u128 << 127);
+        let big = u128::MAX - 10;
+        assert_eq!(calculate_median(&[big, big + 3, 7, u128::MAX]).unwrap(), big + 2);
+
+        assert!(calculate_median(&[]).is_err());
     }
 
     #[test]
@@ -215,13 +1146,154 @@ mod tests {
         let values = vec![90_000_000, 100_000_000, 110_000_000, 120_000_000];
 
         let p25 = calculate_percentile(&values, 25).unwrap();
-        assert_eq!(p25, 90_000_000); // 25th percentile (actual result)
+        assert_eq!(p25, 97_500_000); // 97.5 (interpolated between 90 and 100)
 
         let p75 = calculate_percentile(&values, 75).unwrap();
-        assert_eq!(p75, 115_000_000); // 115.0 (actual result)
+        assert_eq!(p75, 112_500_000); // 112.5 (interpolated between 110 and 120)
 
         let p100 = calculate_percentile(&values, 100).unwrap();
         assert_eq!(p100, 120_000_000); // Maximum value
+
+        // Interpolation across the full u128 range does not overflow
+        assert_eq!(calculate_percentile(&[0, u128::MAX], 50).unwrap(), u128::MAX / 2);
+        assert_eq!(calculate_percentile(&[0, u128::MAX], 99).unwrap(), u128::MAX / 100 * 99 + 55 * 99 / 100); // MAX % 100 == 55
+        assert_eq!(percentile_sorted(&[1, u128::MAX], 50).unwrap(), 1 + (u128::MAX - 1) / 2);
+        assert!(iqr(&[0, 1, u128::MAX - 1, u128::MAX]).is_ok());
+    }
+
+    #[test]
+    fn test_percentile_sorted() {
+        let values = vec![120_000_000, 90_000_000, 110_000_000, 100_000_000, 95_000_000];
+        let mut sorted = values.clone();
+        sorted.sort_unstable();
+
+        for percentile in [0, 1, 25, 33, 50, 75, 99, 100] {
+            assert_eq!(
+                percentile_sorted(&sorted, percentile).unwrap(),
+                calculate_percentile(&values, percentile).unwrap()
+            );
+        }
+
+        assert!(percentile_sorted(&[], 50).is_err());
+        assert!(percentile_sorted(&sorted, 101).is_err());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "sorted ascending")]
+    fn test_percentile_sorted_rejects_unsorted_in_debug() {
+        let _ = percentile_sorted(&[3, 1, 2], 50);
+    }
+
+    #[test]
+    fn test_calculate_atr() {
+        let highs = vec![10_0000_0000, 11_0000_0000, 12_0000_0000, 11_0000_0000];
+        let lows = vec![9_0000_0000, 10_0000_0000, 10_0000_0000, 9_0000_0000];
+        let closes = vec![9_5000_0000, 10_5000_0000, 11_5000_0000, 10_0000_0000];
+        // True ranges: 1.0, 1.5 (gap from 9.5), 2.0, 2.5 (gap from 11.5)
+
+        // Simple average when period covers all bars
+        assert_eq!(calculate_atr(&highs, &lows, &closes, 4).unwrap(), 1_7500_0000);
+
+        // Wilder smoothing: (1.5 * 2 + 2.5) / 3
+        assert_eq!(calculate_atr(&highs, &lows, &closes, 3).unwrap(), 1_8333_3333);
+
+        // Period 1 is the last true range
+        assert_eq!(calculate_atr(&highs, &lows, &closes, 1).unwrap(), 2_5000_0000);
+
+        // Mismatched lengths, bad periods and low > high are rejected
+        assert!(calculate_atr(&highs[..3], &lows, &closes, 2).is_err());
+        assert!(calculate_atr(&highs, &lows, &closes[..3], 2).is_err());
+        assert!(calculate_atr(&highs, &lows, &closes, 0).is_err());
+        assert!(calculate_atr(&highs, &lows, &closes, 5).is_err());
+        assert!(calculate_atr(&[1], &[2], &[1], 1).is_err());
+    }
+
+    #[test]
+    fn test_iqr_outlier_filtering() {
+        let values = vec![
+            10_0000_0000, 11_0000_0000, 12_0000_0000, 13_0000_0000,
+            14_0000_0000, 15_0000_0000, 100_0000_0000,
+        ];
+
+        // Q1 = 11.5, Q3 = 14.5
+        assert_eq!(iqr(&values).unwrap(), 3_0000_0000);
+
+        // The obvious outlier is removed
+        let filtered = filter_outliers_iqr(&values, 15000).unwrap();
+        assert_eq!(filtered, values[..6].to_vec());
+
+        // A clean dataset passes through unchanged
+        let clean = vec![12_0000_0000, 10_0000_0000, 14_0000_0000, 11_0000_0000, 13_0000_0000];
+        assert_eq!(filter_outliers_iqr(&clean, 15000).unwrap(), clean);
+
+        // Too few values should fail
+        assert!(iqr(&[]).is_err());
+        assert!(filter_outliers_iqr(&values[..3], 15000).is_err());
+    }
+
+    #[test]
+    fn test_cumulative_sum() {
+        let deltas = vec![10_000_000, -25_000_000, 5_000_000, 30_000_000, -20_000_000];
+        let cvd = cumulative_sum(&deltas).unwrap();
+        assert_eq!(cvd, vec![10_000_000, -15_000_000, -10_000_000, 20_000_000, 0]);
+
+        let volumes = vec![100_000_000, 50_000_000, 25_000_000];
+        let totals = cumulative_sum_u128(&volumes).unwrap();
+        assert_eq!(totals, vec![100_000_000, 150_000_000, 175_000_000]);
+
+        // Empty input yields an empty series
+        assert!(cumulative_sum(&[]).unwrap().is_empty());
+
+        // Overflow is reported rather than wrapping
+        assert_eq!(cumulative_sum(&[i128::MAX - 1, 1, 1]), Err(FinancialError::Overflow));
+        assert_eq!(cumulative_sum(&[i128::MIN, -1]), Err(FinancialError::Overflow));
+        assert_eq!(cumulative_sum_u128(&[u128::MAX, 1]), Err(FinancialError::Overflow));
+    }
+
+    #[test]
+    fn test_weighted_percentile() {
+        let values = vec![100_000_000, 200_000_000, 300_000_000];
+
+        // Equal weights match the unweighted median
+        let equal = weighted_percentile(&values, &[1, 1, 1], 50).unwrap();
+        assert_eq!(equal, calculate_median(&values).unwrap());
+
+        // Heavy weight on the top value pulls the median towards it
+        let weighted = weighted_percentile(&values, &[1, 1, 10], 50).unwrap();
+        assert_eq!(weighted, 281_818_181);
+        assert!(weighted > equal);
+
+        // Extremes clamp to the smallest and largest values
+        assert_eq!(weighted_percentile(&values, &[1, 1, 10], 0).unwrap(), 100_000_000);
+        assert_eq!(weighted_percentile(&values, &[1, 1, 10], 100).unwrap(), 300_000_000);
+
+        // Input order does not matter
+        let shuffled_values = vec![300_000_000, 100_000_000, 200_000_000];
+        let shuffled = weighted_percentile(&shuffled_values, &[10, 1, 1], 50).unwrap();
+        assert_eq!(shuffled, weighted);
+
+        // Invalid inputs should fail
+        assert!(weighted_percentile(&values, &[1, 1], 50).is_err());
+        assert!(weighted_percentile(&[], &[], 50).is_err());
+        assert!(weighted_percentile(&values, &[1, 1, 1], 101).is_err());
+        assert!(weighted_percentile(&values, &[0, 0, 0], 50).is_err());
+    }
+
+    #[test]
+    fn test_percentile_rank() {
+        let series: Vec<u128> = (1..=100).map(|i| i * 1_000_000).collect();
+
+        assert_eq!(percentile_rank(100_000_000, &series).unwrap(), 100); // Maximum
+        assert_eq!(percentile_rank(1_000_000, &series).unwrap(), 1); // Minimum
+        assert_eq!(percentile_rank(50_000_000, &series).unwrap(), 50); // Middle
+
+        // Values outside the series clamp to 0 and 100
+        assert_eq!(percentile_rank(0, &series).unwrap(), 0);
+        assert_eq!(percentile_rank(u128::MAX, &series).unwrap(), 100);
+
+        // Empty series should fail
+        assert!(percentile_rank(1, &[]).is_err());
     }
 
     #[test]
@@ -253,4 +1325,143 @@ mod tests {
         assert_eq!(integer_sqrt(10).unwrap(), 3);
         assert_eq!(integer_sqrt(15).unwrap(), 3);
     }
+
+    #[test]
+    fn test_rolling_std_dev() {
+        let values = vec![
+            100_000_000, 110_000_000, 90_000_000, 105_000_000,
+            95_000_000, 120_000_000, 80_000_000,
+        ];
+
+        let std_devs = rolling_std_dev(&values, 3, 6).unwrap();
+        assert_eq!(std_devs.len(), values.len() - 3 + 1);
+        for (i, std_dev) in std_devs.iter().enumerate() {
+            assert_eq!(*std_dev, calculate_std_dev(&values[i..i + 3]).unwrap());
+        }
+
+        // Window covering the whole series yields a single value
+        let full = rolling_std_dev(&values, values.len(), 6).unwrap();
+        assert_eq!(full, vec![calculate_std_dev(&values).unwrap()]);
+
+        // Invalid windows
+        assert!(rolling_std_dev(&values, 1, 6).is_err());
+        assert!(rolling_std_dev(&values, values.len() + 1, 6).is_err());
+
+        // Scale is informational; small values at a high scale are fine
+        assert_eq!(rolling_std_dev(&[9, 10, 11], 3, 20).unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn test_z_score() {
+        let series = vec![90_000_000, 100_000_000, 110_000_000]; // mean 100, std dev 10
+
+        // Value at the mean
+        assert_eq!(z_score(100_000_000, &series, 4).unwrap(), 0);
+
+        // One standard deviation above the mean
+        assert_eq!(z_score(110_000_000, &series, 4).unwrap(), 1_0000);
+
+        // Below the mean yields a negative z-score
+        assert_eq!(z_score(85_000_000, &series, 4).unwrap(), -1_5000);
+
+        // Too few values or zero standard deviation should fail
+        assert!(z_score(100_000_000, &[100_000_000], 4).is_err());
+        assert!(z_score(100_000_000, &[100_000_000, 100_000_000], 4).is_err());
+    }
+
+    #[test]
+    fn test_rate_of_change() {
+        // Up-trending series: 100, 110, 121, 133.1
+        let up = vec![100_000_000, 110_000_000, 121_000_000, 133_100_000];
+        assert_eq!(rate_of_change(&up, 1, 4).unwrap(), vec![1000, 1000, 1000]); // +10% each
+        assert_eq!(rate_of_change(&up, 3, 6).unwrap(), vec![331_000]);          // +33.1%
+
+        // Down-trending series: 100, 90, 81
+        let down = vec![100_000_000, 90_000_000, 81_000_000];
+        assert_eq!(rate_of_change(&down, 1, 4).unwrap(), vec![-1000, -1000]);   // -10% each
+        assert_eq!(rate_of_change(&down, 2, 4).unwrap(), vec![-1900]);          // -19%
+
+        // Flat prices yield zero
+        assert_eq!(rate_of_change(&[5, 5, 5], 1, 8).unwrap(), vec![0, 0]);
+
+        // Invalid periods and zero denominators
+        assert!(rate_of_change(&up, 0, 4).is_err());
+        assert!(rate_of_change(&up, up.len(), 4).is_err());
+        assert_eq!(rate_of_change(&[0, 100], 1, 4), Err(FinancialError::DivisionByZero));
+    }
+
+    #[test]
+    fn test_log_returns() {
+        // ln(1.1) = 0.0953101798..., ln(1/1.1) = -0.0953101798...
+        let prices = vec![100_000_000, 110_000_000, 100_000_000];
+        let returns = log_returns(&prices, 8).unwrap();
+        assert_eq!(returns.len(), 2);
+        assert!((returns[0] - 9_531_018).abs() <= 1);
+        assert!((returns[1] + 9_531_018).abs() <= 1);
+
+        // Unchanged price has zero return
+        assert_eq!(log_returns(&[100_000_000, 100_000_000], 8).unwrap(), vec![0]);
+
+        // ln(10) = 2.302585092994..., ln(0.25) = -1.386294361119...
+        let returns = log_returns(&[1_000_000, 10_000_000, 2_500_000], 12).unwrap();
+        assert!((returns[0] - 2_302_585_092_994).abs() <= 1);
+        assert!((returns[1] + 1_386_294_361_120).abs() <= 1);
+
+        // Raw prices above u128::MAX / 1e18 are still supported
+        let big = 1_000_000_000_000_000_000_000u128;
+        assert_eq!(log_returns(&[big, big + 1], 12).unwrap(), vec![0]);
+        let returns = log_returns(&[big, big * 2, u128::MAX], 8).unwrap();
+        assert!((returns[0] - 69_314_718).abs() <= 1);
+        // ln(u128::MAX / 2e21) = 39.6754049782...
+        assert!((returns[1] - 3_967_540_498).abs() <= 1);
+
+        // A single-step ratio beyond the internal range overflows
+        assert_eq!(log_returns(&[1, u128::MAX], 8), Err(FinancialError::Overflow));
+
+        // Too short, zero prices, and unsupported scales should fail
+        assert!(log_returns(&[100_000_000], 8).is_err());
+        assert!(log_returns(&[100_000_000, 0, 100_000_000], 8).is_err());
+        assert!(log_returns(&[100_000_000, 110_000_000], 19).is_err());
+    }
+
+    #[test]
+    fn test_p2_quantile() {
+        // Deterministically shuffled 1..=1000
+        let values: Vec<u128> = (0..1000u128)
+            .map(|i| ((i * 7919) % 1000 + 1) * 1_000_000)
+            .collect();
+
+        for percentile in [50u32, 90, 99] {
+            let mut estimator = P2Quantile::new(percentile * 100).unwrap();
+            for &value in &values {
+                estimator.push(value);
+            }
+            assert_eq!(estimator.count(), 1000);
+
+            // Within 1% of the value range of the exact percentile
+            let exact = calculate_percentile(&values, percentile).unwrap();
+            assert!(estimator.estimate().abs_diff(exact) <= 10_000_000);
+        }
+
+        // Exact for fewer than five observations
+        let mut small = P2Quantile::new(5000).unwrap();
+        assert_eq!(small.estimate(), 0);
+        for value in [300u128, 100, 200] {
+            small.push(value);
+        }
+        assert_eq!(small.estimate(), 200);
+
+        // The 0% and 100% endpoints are the exact minimum and maximum
+        let mut min = P2Quantile::new(0).unwrap();
+        let mut max = P2Quantile::new(10_000).unwrap();
+        for value in 1..=100u128 {
+            min.push(value);
+            max.push(value);
+        }
+        assert_eq!(min.estimate(), 1);
+        assert_eq!(max.estimate(), 100);
+
+        // Quantiles above 100% should fail
+        assert!(P2Quantile::new(10_001).is_err());
+    }
 }
\ No newline at end of file
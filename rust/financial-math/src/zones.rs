@@ -2,10 +2,12 @@
 //!
 //! Zone-based calculations for price levels and tick size normalization.
 
-use crate::{FinancialResult, FinancialError};
+use crate::{FinancialResult, FinancialError, Side, calculate_percentile};
 
 /// Normalize price to tick size using fixed-point arithmetic
 ///
+/// Returns `Overflow` when the nearest tick lies above `u128::MAX`.
+///
 /// # Examples
 /// ```
 /// use financial_math::normalize_price_to_tick;
@@ -28,20 +30,42 @@ pub fn normalize_price_to_tick(price: u128, tick_size: u128) -> FinancialResult<
     // Round to nearest tick (banker's rounding)
     let half_tick = tick_size / 2;
     if remainder > half_tick {
-        Ok(price + (tick_size - remainder))
+        price.checked_add(tick_size - remainder).ok_or(FinancialError::Overflow)
     } else if remainder == half_tick {
         // Banker's rounding: round to even
         let base = price - remainder;
         if (base / tick_size) % 2 == 0 {
             Ok(base)
         } else {
-            Ok(base + tick_size)
+            base.checked_add(tick_size).ok_or(FinancialError::Overflow)
         }
     } else {
         Ok(price - remainder)
     }
 }
 
+/// Normalize a batch of prices to tick size in one pass
+///
+/// # Examples
+/// ```
+/// use financial_math::normalize_prices_to_tick;
+///
+/// let prices = vec![100_0040_0000u128, 100_0060_0000u128];  // 100.004, 100.006
+/// let tick_size = 1_000_000u128;                              // 0.01000000
+/// let normalized = normalize_prices_to_tick(&prices, tick_size).unwrap();
+/// assert_eq!(normalized, vec![100_0000_0000u128, 100_0100_0000u128]);
+/// ```
+pub fn normalize_prices_to_tick(prices: &[u128], tick_size: u128) -> FinancialResult<Vec<u128>> {
+    if tick_size == 0 {
+        return Err(FinancialError::DivisionByZero);
+    }
+
+    prices
+        .iter()
+        .map(|&price| normalize_price_to_tick(price, tick_size))
+        .collect()
+}
+
 /// Calculate price zone using precise arithmetic
 ///
 /// # Examples
@@ -74,6 +98,30 @@ pub fn calculate_zone(
     Ok(zone)
 }
 
+/// Calculate price zone on a grid that starts at `origin` instead of zero
+///
+/// Zones are `[origin + k * zone_size, origin + (k + 1) * zone_size)`; the
+/// returned value is the lower bound of the zone containing `price`.
+///
+/// # Examples
+/// ```
+/// use financial_math::calculate_zone_from_origin;
+///
+/// let price = 103_2000_0000u128;   // 103.20000000
+/// let zone_size = 2_0000_0000u128; // 2.00000000
+/// let origin = 5000_0000u128;      // 0.50000000
+/// let zone = calculate_zone_from_origin(price, zone_size, origin).unwrap();
+/// assert_eq!(zone, 102_5000_0000u128); // 102.50000000
+/// ```
+pub fn calculate_zone_from_origin(price: u128, zone_size: u128, origin: u128) -> FinancialResult<u128> {
+    if zone_size == 0 || price < origin {
+        return Err(FinancialError::InvalidValue);
+    }
+
+    let offset = price - origin;
+    Ok(origin + (offset / zone_size) * zone_size)
+}
+
 /// Calculate price zone with simpler interface
 ///
 /// # Examples
@@ -88,6 +136,33 @@ pub fn price_to_zone(price: u128, tick_size: u128) -> FinancialResult<u128> {
     normalize_price_to_tick(price, tick_size)
 }
 
+/// Derive a zone size from the distribution of recent price ranges
+///
+/// Returns the given percentile (0-100) of `recent_ranges`, so zones widen
+/// when the market moves more. A zero result is rejected with `InvalidValue`
+/// since it cannot be used as a zone size.
+///
+/// # Examples
+/// ```
+/// use financial_math::dynamic_zone_size;
+///
+/// let ranges = vec![1_0000_0000u128, 2_0000_0000, 3_0000_0000]; // 1.0, 2.0, 3.0
+/// let zone_size = dynamic_zone_size(&ranges, 50).unwrap();
+/// assert_eq!(zone_size, 2_0000_0000u128); // 2.00000000
+/// ```
+pub fn dynamic_zone_size(recent_ranges: &[u128], percentile: u32) -> FinancialResult<u128> {
+    if recent_ranges.is_empty() || percentile > 100 {
+        return Err(FinancialError::InvalidValue);
+    }
+
+    let zone_size = calculate_percentile(recent_ranges, percentile)?;
+    if zone_size == 0 {
+        return Err(FinancialError::InvalidValue);
+    }
+
+    Ok(zone_size)
+}
+
 /// Check if price is within a zone range
 ///
 /// # Examples
@@ -126,6 +201,164 @@ pub fn calculate_zone_boundaries(price: u128, zone_size: u128) -> FinancialResul
     Ok((zone_start, zone_end))
 }
 
+/// Count the whole ticks separating two prices (order independent)
+///
+/// # Examples
+/// ```
+/// use financial_math::ticks_between;
+///
+/// let price_a = 100_0000_0000u128;  // 100.00000000
+/// let price_b = 100_0500_0000u128;  // 100.05000000
+/// let tick_size = 1_000_000u128;    // 0.01000000
+/// assert_eq!(ticks_between(price_a, price_b, tick_size).unwrap(), 5);
+/// ```
+pub fn ticks_between(price_a: u128, price_b: u128, tick_size: u128) -> FinancialResult<u128> {
+    if tick_size == 0 {
+        return Err(FinancialError::DivisionByZero);
+    }
+
+    Ok(price_a.abs_diff(price_b) / tick_size)
+}
+
+/// Move a price up (positive `ticks`) or down (negative `ticks`) by whole ticks
+///
+/// # Examples
+/// ```
+/// use financial_math::advance_ticks;
+///
+/// let price = 100_0000_0000u128;  // 100.00000000
+/// let tick_size = 1_000_000u128;  // 0.01000000
+/// assert_eq!(advance_ticks(price, 5, tick_size).unwrap(), 100_0500_0000u128);
+/// assert_eq!(advance_ticks(price, -5, tick_size).unwrap(), 99_9500_0000u128);
+/// ```
+pub fn advance_ticks(price: u128, ticks: i64, tick_size: u128) -> FinancialResult<u128> {
+    if tick_size == 0 {
+        return Err(FinancialError::DivisionByZero);
+    }
+
+    let offset = (ticks.unsigned_abs() as u128)
+        .checked_mul(tick_size)
+        .ok_or(FinancialError::Overflow)?;
+
+    if ticks >= 0 {
+        price.checked_add(offset).ok_or(FinancialError::Overflow)
+    } else {
+        price.checked_sub(offset).ok_or(FinancialError::NegativeValue)
+    }
+}
+
+/// Generate an evenly spaced ladder of order prices away from a reference
+///
+/// The reference is first normalized to the tick grid; bid ladders step down
+/// and ask ladders step up by `spacing_ticks` ticks per level. The reference
+/// itself is not included.
+///
+/// # Examples
+/// ```
+/// use financial_math::{generate_ladder, Side};
+///
+/// let reference = 100_0000_0000u128;  // 100.00000000
+/// let tick_size = 1_000_000u128;      // 0.01000000
+/// let ladder = generate_ladder(reference, tick_size, 3, 5, Side::Bid).unwrap();
+/// assert_eq!(ladder, vec![99_9500_0000, 99_9000_0000, 99_8500_0000]);
+/// ```
+pub fn generate_ladder(
+    reference: u128,
+    tick_size: u128,
+    count: usize,
+    spacing_ticks: u32,
+    direction: Side
+) -> FinancialResult<Vec<u128>> {
+    if tick_size == 0 {
+        return Err(FinancialError::DivisionByZero);
+    }
+    if spacing_ticks == 0 {
+        return Err(FinancialError::InvalidValue);
+    }
+
+    let step = match direction {
+        Side::Bid => -(spacing_ticks as i64),
+        Side::Ask => spacing_ticks as i64,
+    };
+
+    let mut price = normalize_price_to_tick(reference, tick_size)?;
+    let mut ladder = Vec::with_capacity(count);
+    for _ in 0..count {
+        price = advance_ticks(price, step, tick_size)?;
+        ladder.push(price);
+    }
+
+    Ok(ladder)
+}
+
+/// Tiered tick size table for instruments whose tick depends on price band
+///
+/// Each band is a `(price_threshold, tick_size)` pair; a band applies from its
+/// threshold (inclusive) up to the next band's threshold. Prices below the
+/// lowest threshold use the first band's tick.
+///
+/// # Examples
+/// ```
+/// use financial_math::TickTable;
+///
+/// // 0.01 ticks below 100.00, 0.10 ticks from 100.00 upwards
+/// let table = TickTable::new(vec![
+///     (0, 1_000_000u128),
+///     (100_0000_0000u128, 10_000_000u128),
+/// ]).unwrap();
+///
+/// assert_eq!(table.tick_size_for(99_9900_0000), 1_000_000);
+/// assert_eq!(table.tick_size_for(100_0000_0000), 10_000_000);
+/// assert_eq!(table.normalize(123_4567_8901).unwrap(), 123_5000_0000);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TickTable {
+    /// Bands sorted by ascending price threshold
+    bands: Vec<(u128, u128)>,
+}
+
+impl TickTable {
+    /// Create a tick table from `(price_threshold, tick_size)` bands in any order
+    ///
+    /// Returns `InvalidValue` for an empty table or duplicate thresholds and
+    /// `DivisionByZero` for a zero tick size.
+    pub fn new(mut bands: Vec<(u128, u128)>) -> FinancialResult<Self> {
+        if bands.is_empty() {
+            return Err(FinancialError::InvalidValue);
+        }
+        if bands.iter().any(|&(_, tick_size)| tick_size == 0) {
+            return Err(FinancialError::DivisionByZero);
+        }
+
+        bands.sort_unstable_by_key(|&(threshold, _)| threshold);
+        // Duplicate thresholds would make the applicable tick ambiguous
+        if bands.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+            return Err(FinancialError::InvalidValue);
+        }
+
+        Ok(Self { bands })
+    }
+
+    /// Bands sorted by ascending price threshold
+    pub fn bands(&self) -> &[(u128, u128)] {
+        &self.bands
+    }
+
+    /// Tick size applicable at the given price
+    pub fn tick_size_for(&self, price: u128) -> u128 {
+        // Number of bands whose threshold is <= price
+        let idx = self.bands.partition_point(|&(threshold, _)| threshold <= price);
+        self.bands[idx.saturating_sub(1)].1
+    }
+
+    /// Normalize a price to the tick size of its band
+    ///
+    /// Returns `Overflow` when the nearest tick lies above `u128::MAX`.
+    pub fn normalize(&self, price: u128) -> FinancialResult<u128> {
+        normalize_price_to_tick(price, self.tick_size_for(price))
+    }
+}
+
 /// Calculate support/resistance levels based on price history
 ///
 /// # Examples
@@ -186,6 +419,32 @@ mod tests {
 
         // Zero tick size should fail
         assert!(normalize_price_to_tick(price, 0).is_err());
+
+        // The nearest tick above u128::MAX overflows
+        assert_eq!(normalize_price_to_tick(u128::MAX, 10), Err(FinancialError::Overflow));
+    }
+
+    #[test]
+    fn test_normalize_prices_to_tick() {
+        let tick_size = 1_000_000u128;  // 0.01000000
+        let prices = vec![
+            123_4567_8901u128,
+            100_0050_0000u128,
+            100_0150_0000u128,
+            99_9999_9999u128,
+            0u128,
+        ];
+
+        let normalized = normalize_prices_to_tick(&prices, tick_size).unwrap();
+        let expected: Vec<u128> = prices
+            .iter()
+            .map(|&price| normalize_price_to_tick(price, tick_size).unwrap())
+            .collect();
+        assert_eq!(normalized, expected);
+
+        // Empty batch and zero tick size
+        assert!(normalize_prices_to_tick(&[], tick_size).unwrap().is_empty());
+        assert!(normalize_prices_to_tick(&prices, 0).is_err());
     }
 
     #[test]
@@ -197,6 +456,48 @@ mod tests {
         assert_eq!(zone % (zone_ticks * 10u128.pow(8)), 0);
     }
 
+    #[test]
+    fn test_calculate_zone_from_origin() {
+        let zone_size = 2_0000_0000u128; // 2.00000000
+        let origin = 5000_0000u128;      // 0.50000000
+
+        // Buckets are [0.5, 2.5), [2.5, 4.5), ... rather than [0, 2), [2, 4), ...
+        assert_eq!(calculate_zone_from_origin(origin, zone_size, origin).unwrap(), origin);
+        assert_eq!(calculate_zone_from_origin(2_4999_9999, zone_size, origin).unwrap(), origin);
+        assert_eq!(calculate_zone_from_origin(2_5000_0000, zone_size, origin).unwrap(), 2_5000_0000);
+        assert_eq!(calculate_zone_from_origin(4_0000_0000, zone_size, origin).unwrap(), 2_5000_0000);
+
+        // Zero origin matches plain zone boundaries
+        let (zone_start, _) = calculate_zone_boundaries(4_0000_0000, zone_size).unwrap();
+        assert_eq!(calculate_zone_from_origin(4_0000_0000, zone_size, 0).unwrap(), zone_start);
+
+        // Zero zone size and prices below the origin are rejected
+        assert!(calculate_zone_from_origin(4_0000_0000, 0, origin).is_err());
+        assert!(calculate_zone_from_origin(4000_0000, zone_size, origin).is_err());
+    }
+
+    #[test]
+    fn test_dynamic_zone_size() {
+        let tight = vec![1_000_000u128, 2_000_000, 1_500_000, 2_500_000];      // 0.01 - 0.025
+        let wide = vec![10_000_000u128, 40_000_000, 25_000_000, 30_000_000];   // 0.10 - 0.40
+
+        let tight_zone = dynamic_zone_size(&tight, 75).unwrap();
+        let wide_zone = dynamic_zone_size(&wide, 75).unwrap();
+        assert!(wide_zone > tight_zone);
+        assert_eq!(tight_zone, calculate_percentile(&tight, 75).unwrap());
+
+        // Higher percentiles never shrink the zone
+        assert!(dynamic_zone_size(&wide, 90).unwrap() >= dynamic_zone_size(&wide, 50).unwrap());
+
+        // Empty input, percentile above 100, and zero-size zones are rejected
+        assert!(dynamic_zone_size(&[], 50).is_err());
+        assert!(dynamic_zone_size(&tight, 101).is_err());
+        assert!(dynamic_zone_size(&[0, 0, 0], 50).is_err());
+
+        // Extreme ranges interpolate without overflowing
+        assert_eq!(dynamic_zone_size(&[1, u128::MAX], 50).unwrap(), 1 + (u128::MAX - 1) / 2);
+    }
+
     #[test]
     fn test_price_in_zone() {
         let price = 100_5000_0000u128;  // 100.50000000
@@ -223,6 +524,99 @@ mod tests {
         assert!(calculate_zone_boundaries(price, 0).is_err());
     }
 
+    #[test]
+    fn test_tick_distance() {
+        let tick_size = 1_000_000u128;    // 0.01000000
+        let price_a = 100_0000_0000u128;  // 100.00000000
+        let price_b = 100_0500_0000u128;  // 100.05000000
+
+        // 5-tick gap in either order
+        assert_eq!(ticks_between(price_a, price_b, tick_size).unwrap(), 5);
+        assert_eq!(ticks_between(price_b, price_a, tick_size).unwrap(), 5);
+        assert_eq!(ticks_between(price_a, price_a, tick_size).unwrap(), 0);
+
+        // Advancing by the gap lands on the other price
+        assert_eq!(advance_ticks(price_a, 5, tick_size).unwrap(), price_b);
+        assert_eq!(advance_ticks(price_b, -5, tick_size).unwrap(), price_a);
+
+        // Advancing below zero should fail
+        assert_eq!(advance_ticks(3_000_000, -4, tick_size), Err(FinancialError::NegativeValue));
+
+        // Zero tick size should fail
+        assert!(ticks_between(price_a, price_b, 0).is_err());
+        assert!(advance_ticks(price_a, 1, 0).is_err());
+    }
+
+    #[test]
+    fn test_generate_ladder() {
+        let reference = 100_0000_0000u128;  // 100.00000000
+        let tick_size = 1_000_000u128;      // 0.01000000
+
+        // 5-level bid ladder two ticks apart
+        let bids = generate_ladder(reference, tick_size, 5, 2, Side::Bid).unwrap();
+        assert_eq!(bids, vec![
+            99_9800_0000, 99_9600_0000, 99_9400_0000, 99_9200_0000, 99_9000_0000,
+        ]);
+
+        // Ask ladder from an off-grid reference stays tick aligned
+        let asks = generate_ladder(reference + 300_000, tick_size, 3, 1, Side::Ask).unwrap();
+        assert_eq!(asks, vec![100_0100_0000, 100_0200_0000, 100_0300_0000]);
+        for window in asks.windows(2) {
+            assert_eq!(window[1] - window[0], tick_size);
+            assert_eq!(window[1] % tick_size, 0);
+        }
+
+        // Empty ladder
+        assert!(generate_ladder(reference, tick_size, 0, 1, Side::Ask).unwrap().is_empty());
+
+        // Zero tick or spacing and ladders below zero should fail
+        assert!(generate_ladder(reference, 0, 5, 1, Side::Bid).is_err());
+        assert!(generate_ladder(reference, tick_size, 5, 0, Side::Bid).is_err());
+        assert_eq!(
+            generate_ladder(3_000_000, tick_size, 5, 1, Side::Bid),
+            Err(FinancialError::NegativeValue)
+        );
+    }
+
+    #[test]
+    fn test_tick_table() {
+        // 0.01 ticks below 100.00, 0.10 ticks from 100.00 upwards
+        let fine = 1_000_000u128;
+        let coarse = 10_000_000u128;
+        let threshold = 100_0000_0000u128;
+        let table = TickTable::new(vec![(threshold, coarse), (0, fine)]).unwrap();
+
+        // Bands are sorted on construction
+        assert_eq!(table.bands(), &[(0, fine), (threshold, coarse)]);
+
+        // Boundary: threshold itself belongs to the upper band
+        assert_eq!(table.tick_size_for(threshold - 1), fine);
+        assert_eq!(table.tick_size_for(threshold), coarse);
+        assert_eq!(table.tick_size_for(threshold + 1), coarse);
+        assert_eq!(table.tick_size_for(0), fine);
+
+        // Normalization uses the band's tick
+        assert_eq!(table.normalize(99_1234_0000).unwrap(), 99_1200_0000);    // 99.1234 -> 99.12
+        assert_eq!(table.normalize(123_4567_8901).unwrap(), 123_5000_0000);  // 123.4567 -> 123.50
+
+        // Rounding up past u128::MAX is reported rather than panicking
+        let table = TickTable::new(vec![(0, 10)]).unwrap();
+        assert_eq!(table.normalize(u128::MAX), Err(FinancialError::Overflow));
+        assert_eq!(table.normalize(u128::MAX - 5).unwrap(), u128::MAX - 5);
+
+        // Prices below the lowest threshold use the first band
+        let table = TickTable::new(vec![(10_0000_0000, fine)]).unwrap();
+        assert_eq!(table.tick_size_for(5_0000_0000), fine);
+
+        // Guards
+        assert_eq!(TickTable::new(vec![]), Err(FinancialError::InvalidValue));
+        assert_eq!(TickTable::new(vec![(0, 0)]), Err(FinancialError::DivisionByZero));
+        assert_eq!(
+            TickTable::new(vec![(0, 1), (100, 10), (100, 5)]),
+            Err(FinancialError::InvalidValue)
+        );
+    }
+
     #[test]
     fn test_support_resistance() {
         let prices = vec![
@@ -1,13 +1,154 @@
 // Simple test to load the Rust bindings
+let bindings;
 try {
-    const bindings = require("./target/release/index.node");
+    bindings = require("./target/release/index.node");
     console.log("✅ Rust bindings loaded successfully!");
     console.log("Available functions:", Object.keys(bindings));
-
-    // Test a simple function
-    const result = bindings.price_to_int(123.456789);
-    console.log("price_to_int(123.456789) =", result);
 } catch (error) {
     console.error("❌ Failed to load Rust bindings:", error.message);
     console.error("Error details:", error);
 }
+
+// Functional checks; a failed assertion sets a non-zero exit code
+if (bindings) {
+    try {
+        // Test a simple function
+        const result = bindings.price_to_int(123.456789);
+        console.log("price_to_int(123.456789) =", result);
+
+        // calculate_spread takes (bid, ask) and returns ask - bid
+        const spread = bindings.calculate_spread("100000000", "101000000");
+        console.log('calculate_spread("100000000", "101000000") =', spread);
+        if (spread !== "1000000") {
+            throw new Error(`Expected spread "1000000", got "${spread}"`);
+        }
+
+        // Batch conversions round-trip and report the first invalid index
+        const fixedPrices = bindings.prices_to_int(new Float64Array([123.456789, 100]));
+        console.log("prices_to_int([123.456789, 100]) =", fixedPrices);
+        const floatPrices = bindings.ints_to_prices(fixedPrices);
+        console.log("ints_to_prices(...) =", floatPrices);
+        if (floatPrices[0] !== 123.456789 || floatPrices[1] !== 100) {
+            throw new Error(`Unexpected batch round-trip result ${floatPrices}`);
+        }
+        try {
+            bindings.prices_to_int(new Float64Array([100, NaN]));
+            throw new Error("Expected prices_to_int to reject NaN");
+        } catch (batchError) {
+            if (!batchError.message.includes("InvalidValueAt(1)")) {
+                throw batchError;
+            }
+        }
+
+        // 50000.00 x 0.5 = 25000.00 at scale 8
+        const notional = bindings.notional("5000000000000", 8, "50000000", 8, 8);
+        console.log('notional("5000000000000", 8, "50000000", 8, 8) =', notional);
+        if (notional !== "2500000000000") {
+            throw new Error(`Expected notional "2500000000000", got "${notional}"`);
+        }
+        for (const badScale of [-1, NaN, 2.7, 39]) {
+            try {
+                bindings.notional("5000000000000", badScale, "50000000", 8, 8);
+                throw new Error(`Expected notional to reject scale ${badScale}`);
+            } catch (scaleError) {
+                if (scaleError.code !== "INVALID_SCALE") {
+                    throw scaleError;
+                }
+            }
+        }
+
+        // Errors carry a stable code alongside the message
+        try {
+            bindings.safe_divide("100000000", "0");
+            throw new Error("Expected safe_divide to reject division by zero");
+        } catch (divideError) {
+            console.log("safe_divide by zero code =", divideError.code);
+            if (divideError.code !== "DIVISION_BY_ZERO") {
+                throw divideError;
+            }
+        }
+
+        // Custom scales round-trip through float_to_fixed / fixed_to_float
+        for (const [value, scale, expected] of [
+            [123.45, 2, "12345"],
+            [0.123456789012, 12, "123456789012"],
+        ]) {
+            const fixed = bindings.float_to_fixed(value, scale);
+            const back = bindings.fixed_to_float(fixed, scale);
+            console.log(`float_to_fixed(${value}, ${scale}) =`, fixed, "->", back);
+            if (fixed !== expected || back !== value) {
+                throw new Error(`Unexpected round-trip at scale ${scale}: ${fixed} -> ${back}`);
+            }
+        }
+
+        // Every rejected scale carries the same error code
+        for (const badScale of [0, -1, 2.5, 39]) {
+            for (const call of [
+                () => bindings.float_to_fixed(1.5, badScale),
+                () => bindings.fixed_to_float("15", badScale),
+            ]) {
+                try {
+                    call();
+                    throw new Error(`Expected scale ${badScale} to be rejected`);
+                } catch (scaleError) {
+                    if (scaleError.code !== "INVALID_SCALE") {
+                        throw scaleError;
+                    }
+                }
+            }
+        }
+
+        // Batch tick normalization matches the per-price binding
+        const rawPrices = ["12345678901", "10000500000", "9999999999"];
+        const normalized = bindings.normalize_prices_to_tick(rawPrices, "1000000");
+        console.log("normalize_prices_to_tick(...) =", normalized);
+        rawPrices.forEach((price, index) => {
+            const single = bindings.normalize_price_to_tick(price, "1000000");
+            if (normalized[index] !== single) {
+                throw new Error(`Batch mismatch at ${index}: ${normalized[index]} vs ${single}`);
+            }
+        });
+
+        // Decimal strings convert exactly, bypassing f64
+        const fromString = bindings.price_string_to_int("0.1");
+        console.log('price_string_to_int("0.1") =', fromString);
+        if (fromString !== "10000000" || fromString !== bindings.price_to_int(0.1)) {
+            throw new Error(`Unexpected price_string_to_int result ${fromString}`);
+        }
+        const longPrice = bindings.price_string_to_int("1234567890.12345678");
+        console.log('price_string_to_int("1234567890.12345678") =', longPrice,
+            "vs price_to_int =", bindings.price_to_int(1234567890.12345678));
+        if (longPrice !== "123456789012345678") {
+            throw new Error(`Expected exact string conversion, got ${longPrice}`);
+        }
+
+        // Single-pass summary matches the individual statistics bindings
+        const series = ["90000000", "100000000", "110000000", "105000000"];
+        const stats = bindings.summary_stats(series, 6);
+        console.log("summary_stats(...) =", stats);
+        if (stats.min !== bindings.calculate_min(series)
+            || stats.max !== bindings.calculate_max(series)
+            || stats.mean !== bindings.calculate_mean(series)
+            || stats.stdDev !== "8539125") {
+            throw new Error(`Unexpected summary_stats result ${JSON.stringify(stats)}`);
+        }
+        try {
+            bindings.summary_stats(series, 1.5);
+            throw new Error("Expected summary_stats to reject a fractional scale");
+        } catch (scaleError) {
+            if (scaleError.code !== "INVALID_SCALE") {
+                throw scaleError;
+            }
+        }
+
+        // Quotient and remainder come back together as strings
+        const split = bindings.divide_with_remainder("100", "3");
+        console.log('divide_with_remainder("100", "3") =', split);
+        if (split.quotient !== "33" || split.remainder !== "1") {
+            throw new Error(`Unexpected divide_with_remainder result ${JSON.stringify(split)}`);
+        }
+    } catch (error) {
+        console.error("❌ Binding check failed:", error.message);
+        process.exitCode = 1;
+    }
+}